@@ -0,0 +1,69 @@
+// Compare collision candidate lookup through the spatial grid against
+// checking every pair, for growing numbers of enemies and bubbles.
+//
+// cargo bench --bench spatial_grid
+use bevy::prelude::*;
+use bubble::spatial::SpatialGrid;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+const ARENA_HALF_SIZE: Vec2 = Vec2::new(640.0, 360.0);
+const HIT_RADIUS: f32 = 20.0;
+
+fn random_points(rng: &mut StdRng, count: usize) -> Vec<Vec2> {
+    (0..count)
+        .map(|_| {
+            Vec2::new(
+                rng.gen_range(-ARENA_HALF_SIZE.x..=ARENA_HALF_SIZE.x),
+                rng.gen_range(-ARENA_HALF_SIZE.y..=ARENA_HALF_SIZE.y),
+            )
+        })
+        .collect()
+}
+
+fn collisions(c: &mut Criterion) {
+    let mut group = c.benchmark_group("collisions");
+
+    for count in [100, 1_000, 5_000] {
+        let mut rng = StdRng::seed_from_u64(0);
+        let enemies = random_points(&mut rng, count);
+        let bubbles = random_points(&mut rng, count);
+
+        group.bench_with_input(BenchmarkId::new("brute_force", count), &count, |b, _| {
+            b.iter(|| {
+                let mut hits = 0;
+                for bubble in &bubbles {
+                    for enemy in &enemies {
+                        if bubble.distance_squared(*enemy) <= HIT_RADIUS * HIT_RADIUS {
+                            hits += 1;
+                        }
+                    }
+                }
+                black_box(hits)
+            })
+        });
+
+        // Rebuild included, as the game does every tick
+        let mut grid = SpatialGrid::default();
+        group.bench_with_input(BenchmarkId::new("spatial_grid", count), &count, |b, _| {
+            b.iter(|| {
+                grid.clear();
+                for (index, enemy) in enemies.iter().enumerate() {
+                    grid.insert(Entity::from_raw(index as u32), *enemy, 0.0);
+                }
+
+                let mut hits = 0;
+                for bubble in &bubbles {
+                    hits += grid.query(*bubble, HIT_RADIUS).count();
+                }
+                black_box(hits)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, collisions);
+criterion_main!(benches);
@@ -0,0 +1,57 @@
+// Plays rounds without a window: the ship spins its aim and fires constantly
+// until it dies, then the score is printed. Pass a seed as the first argument
+// to replay a run.
+use bevy::prelude::*;
+use bubble::{GameRng, GameState, HeadlessPlugins, PlayerInput, RngPlugin, Score, WaveDirector};
+
+const ROUNDS: u32 = 3;
+const MAX_FRAMES_PER_ROUND: u32 = 60 * 60 * 5;
+
+fn main() {
+    let seed = std::env::args().nth(1).and_then(|seed| seed.parse().ok());
+
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed }));
+    println!("Seed: {}", app.world().resource::<GameRng>().seed());
+
+    for round in 1..=ROUNDS {
+        let mut frames = 0;
+        while frames < MAX_FRAMES_PER_ROUND {
+            let angle = frames as f32 * 0.05;
+            let mut input = app.world_mut().resource_mut::<PlayerInput>();
+            input.aim_keys = Vec2::from_angle(angle);
+            input.aim_keys_pressed = true;
+
+            app.update();
+            frames += 1;
+
+            if *app.world().resource::<State<GameState>>() == GameState::GameOver {
+                break;
+            }
+        }
+
+        let score = app.world().resource::<Score>();
+        let wave = app.world().resource::<WaveDirector>().wave;
+        println!(
+            "Round {round}: score {} on wave {wave} after {frames} frames",
+            score.value as u32
+        );
+
+        // A round that hit the frame limit ends like a lost one, so its
+        // entities are cleaned up before the replay
+        if *app.world().resource::<State<GameState>>() != GameState::GameOver {
+            app.world_mut()
+                .resource_mut::<NextState<GameState>>()
+                .set(GameState::Dying);
+            while *app.world().resource::<State<GameState>>() != GameState::GameOver {
+                app.update();
+            }
+        }
+
+        // Same as pressing Replay
+        app.world_mut()
+            .resource_mut::<NextState<GameState>>()
+            .set(GameState::Starting);
+        app.update();
+    }
+}
@@ -0,0 +1,126 @@
+use bevy::prelude::*;
+use bevy::render::camera::ScalingMode;
+use serde::Deserialize;
+
+use crate::config::BorderConfig;
+
+// Play field in logical units, centered on the origin. The size never follows
+// the window: the camera scales the arena to fit and letterboxes the rest, so
+// every window size plays by the same rules.
+#[derive(Resource, Debug, Clone, Copy)]
+pub struct Arena {
+    pub width: f32,
+    pub height: f32,
+}
+
+// What happens at the edge of the arena. Every moving thing follows the same
+// rule.
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub enum Topology {
+    Wrap,  // Leave one side, come back in on the other
+    Walls, // Bounce off the arena edge
+    #[default]
+    Hazard, // Bounce off a border zone that damages the ship
+}
+
+impl Default for Arena {
+    fn default() -> Self {
+        Self {
+            width: 1280.0,
+            height: 720.0,
+        }
+    }
+}
+
+impl Arena {
+    pub fn half_size(&self) -> Vec2 {
+        Vec2::new(self.width, self.height) / 2.0
+    }
+
+    // Keep a moving body in the arena according to the border topology.
+    // Returns true when it bounced off a wall or the hazard border.
+    pub fn apply_topology(
+        &self,
+        border: &BorderConfig,
+        position: &mut Vec3,
+        velocity: &mut Vec2,
+    ) -> bool {
+        let half_size = self.half_size();
+        match border.topology {
+            Topology::Wrap => {
+                let size = Vec2::new(self.width, self.height);
+                for axis in 0..2 {
+                    if position[axis] > half_size[axis] {
+                        position[axis] -= size[axis];
+                    } else if position[axis] < -half_size[axis] {
+                        position[axis] += size[axis];
+                    }
+                }
+                false
+            }
+            Topology::Walls => {
+                let bounced = reflect(half_size, position, velocity);
+                *position = position
+                    .truncate()
+                    .clamp(-half_size, half_size)
+                    .extend(position.z);
+                bounced
+            }
+            Topology::Hazard => {
+                let limit = (half_size - Vec2::splat(border.width)).max(Vec2::ZERO);
+                reflect(limit, position, velocity)
+            }
+        }
+    }
+
+    fn scaling_mode(&self) -> ScalingMode {
+        ScalingMode::AutoMin {
+            min_width: self.width,
+            min_height: self.height,
+        }
+    }
+}
+
+// Turn a body around on each axis it is crossing `limit` on. Returns true if it
+// was turned.
+fn reflect(limit: Vec2, position: &Vec3, velocity: &mut Vec2) -> bool {
+    let mut reflected = false;
+    for axis in 0..2 {
+        let outside = position[axis].abs() > limit[axis];
+        let moving_out = position[axis] * velocity[axis] > 0.0;
+        if outside && moving_out {
+            velocity[axis] = -velocity[axis];
+            reflected = true;
+        }
+    }
+    reflected
+}
+
+pub struct ArenaPlugin;
+
+impl Plugin for ArenaPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Arena>()
+            .add_systems(Startup, setup_camera)
+            .add_systems(
+                PreUpdate,
+                update_camera_scaling.run_if(resource_changed::<Arena>),
+            );
+    }
+}
+
+fn setup_camera(mut commands: Commands, arena: Res<Arena>) {
+    commands.spawn((
+        Camera2d,
+        OrthographicProjection {
+            scaling_mode: arena.scaling_mode(),
+            ..OrthographicProjection::default_2d()
+        },
+    ));
+}
+
+fn update_camera_scaling(arena: Res<Arena>, mut query: Query<&mut OrthographicProjection>) {
+    for mut projection in &mut query {
+        projection.scaling_mode = arena.scaling_mode();
+    }
+}
@@ -0,0 +1,154 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+use crate::bubble::BubbleShot;
+use crate::enemy::{EnemyDestroyed, EnemyHit};
+use crate::game_flow::GameState;
+use crate::pickup::PickupCollected;
+use crate::projectile::ProjectilePopped;
+use crate::ship::{ShipBounced, ShootingState};
+
+// Add audio resource
+#[derive(Resource, Default)]
+pub struct GameAudio {
+    pub bubble_shoot: Handle<AudioSource>,
+    pub pop: Handle<AudioSource>,
+    pub bounce: Handle<AudioSource>,
+    pub drip: Handle<AudioSource>,
+}
+
+// Add drip timer resource
+#[derive(Resource, Deref, DerefMut)]
+struct DripTimer(Timer);
+
+pub struct AudioPlugin;
+
+impl Plugin for AudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(DripTimer(Timer::from_seconds(0.05, TimerMode::Repeating)))
+            .add_event::<BubbleShot>()
+            .add_event::<EnemyDestroyed>()
+            .add_event::<ShipBounced>()
+            .add_event::<EnemyHit>()
+            .add_event::<ProjectilePopped>()
+            .add_event::<PickupCollected>()
+            .add_systems(Startup, load_audio)
+            .add_systems(
+                Update,
+                (
+                    handle_bubble_sound,
+                    handle_enemy_death_sound,
+                    handle_ship_bounce_sound,
+                    handle_enemy_hit_sound,
+                    handle_projectile_popped_sound,
+                    handle_pickup_sound,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+fn load_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
+    // Load and store audio assets
+    commands.insert_resource(GameAudio {
+        bubble_shoot: asset_server.load("sounds/bubble.wav"),
+        pop: asset_server.load("sounds/pop.wav"),
+        bounce: asset_server.load("sounds/bounce.wav"),
+        drip: asset_server.load("sounds/drip.wav"),
+    });
+}
+
+// Update sound system to use timer
+fn handle_bubble_sound(
+    mut commands: Commands,
+    mut bubble_shot: EventReader<BubbleShot>,
+    mut query: Query<&mut ShootingState>,
+    audio: Res<GameAudio>,
+    time: Res<Time>,
+) {
+    if let Ok(mut shooting) = query.get_single_mut() {
+        shooting.sound_timer.tick(time.delta());
+
+        // Only play one sound per timer tick
+        if shooting.sound_timer.finished() && bubble_shot.read().next().is_some() {
+            commands.spawn((
+                AudioPlayer::new(audio.bubble_shoot.clone()),
+                PlaybackSettings::DESPAWN,
+            ));
+            shooting.sound_timer.reset();
+        }
+    }
+}
+
+// Add system to handle enemy death sound
+fn handle_enemy_death_sound(
+    mut commands: Commands,
+    mut enemy_destroyed: EventReader<EnemyDestroyed>,
+    audio: Res<GameAudio>,
+) {
+    for _ in enemy_destroyed.read() {
+        commands.spawn((
+            AudioPlayer::new(audio.pop.clone()),
+            PlaybackSettings::DESPAWN,
+        ));
+    }
+}
+
+fn handle_ship_bounce_sound(
+    mut commands: Commands,
+    mut ship_bounced: EventReader<ShipBounced>,
+    audio: Res<GameAudio>,
+) {
+    for _ in ship_bounced.read() {
+        commands.spawn((
+            AudioPlayer::new(audio.bounce.clone()),
+            PlaybackSettings::DESPAWN,
+        ));
+    }
+}
+
+// Update enemy hit sound handler to use timer
+fn handle_enemy_hit_sound(
+    mut commands: Commands,
+    mut enemy_hit: EventReader<EnemyHit>,
+    mut drip_timer: ResMut<DripTimer>,
+    audio: Res<GameAudio>,
+    time: Res<Time>,
+) {
+    drip_timer.tick(time.delta());
+
+    // Only play one sound per timer tick
+    if drip_timer.finished() && enemy_hit.read().next().is_some() {
+        commands.spawn((
+            AudioPlayer::new(audio.drip.clone()),
+            PlaybackSettings::DESPAWN,
+        ));
+        drip_timer.reset();
+    }
+}
+
+fn handle_projectile_popped_sound(
+    mut commands: Commands,
+    mut projectile_popped: EventReader<ProjectilePopped>,
+    audio: Res<GameAudio>,
+) {
+    for _ in projectile_popped.read() {
+        commands.spawn((
+            AudioPlayer::new(audio.pop.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::new(0.5)),
+        ));
+    }
+}
+
+fn handle_pickup_sound(
+    mut commands: Commands,
+    mut pickup_collected: EventReader<PickupCollected>,
+    audio: Res<GameAudio>,
+) {
+    for _ in pickup_collected.read() {
+        commands.spawn((
+            AudioPlayer::new(audio.bubble_shoot.clone()),
+            PlaybackSettings::DESPAWN.with_speed(0.7),
+        ));
+    }
+}
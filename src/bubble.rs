@@ -0,0 +1,718 @@
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::arena::Arena;
+use crate::collision::{
+    resolve_overlap, BubbleBubbleCollision, Collider, CollisionLayer, CollisionSet,
+};
+use crate::config::GameConfig;
+use crate::enemy::update_spawn_warnings;
+use crate::game_flow::{GameSet, GameState, GameplayObject, SimulationSet};
+use crate::input::PlayerInput;
+use crate::physics::{drag_factor, interpolated_position, PreviousPosition, Velocity};
+use crate::pickup::{drop_pickups, PickupKind, PowerUps};
+use crate::rng::GameRng;
+use crate::ship::{check_game_over, AimControl, Ship, ShootingState};
+
+#[derive(Component)]
+pub struct Bubble {
+    pub weapon: BubbleWeapon,
+    pub color: Color,
+    pub size: f32,
+    pub lifetime: Timer,
+}
+
+impl Bubble {
+    // Damage dealt to an enemy the bubble hits
+    pub fn damage(&self, config: &GameConfig) -> f32 {
+        match self.weapon {
+            // Bigger bubbles hit harder
+            BubbleWeapon::Standard => self.size * config.bubble.damage_per_size,
+            BubbleWeapon::Sticky => config.weapons.sticky.damage,
+            BubbleWeapon::Heavy => config.weapons.heavy.damage,
+            BubbleWeapon::Shotgun => config.weapons.shotgun.damage,
+            BubbleWeapon::Mine => config.weapons.mine.damage,
+            // Scales with how long it was charged
+            BubbleWeapon::Charge => self.size * config.weapons.charge.damage_per_size,
+        }
+    }
+}
+
+// What bubbles do when they touch each other
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Default)]
+pub enum BubbleInteraction {
+    #[default]
+    Ignore, // Pass through each other
+    Merge,  // Join into one bigger bubble of the same weapon
+    Bounce, // Bounce apart
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BubbleWeapon {
+    #[default]
+    Standard, // Stream of random bubbles
+    Sticky,  // Slows down the enemies it hits
+    Heavy,   // Slow and big, knocks enemies back
+    Shotgun, // Burst of short-lived pellets
+    Mine,    // Drifts to a stop and blows up on contact
+    Charge,  // Inflates while fire is held, launches on release
+}
+
+impl BubbleWeapon {
+    // In number key order
+    pub const ALL: [BubbleWeapon; 6] = [
+        BubbleWeapon::Standard,
+        BubbleWeapon::Sticky,
+        BubbleWeapon::Heavy,
+        BubbleWeapon::Shotgun,
+        BubbleWeapon::Mine,
+        BubbleWeapon::Charge,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            BubbleWeapon::Standard => "Bubbles",
+            BubbleWeapon::Sticky => "Sticky",
+            BubbleWeapon::Heavy => "Heavy",
+            BubbleWeapon::Shotgun => "Shotgun",
+            BubbleWeapon::Mine => "Mines",
+            BubbleWeapon::Charge => "Charge",
+        }
+    }
+
+    // Bubble supply used per shot. A charged bubble uses more as it grows.
+    pub fn cost(self, config: &GameConfig) -> f32 {
+        match self {
+            BubbleWeapon::Standard => config.bubble.cost,
+            BubbleWeapon::Sticky => config.weapons.sticky.cost,
+            BubbleWeapon::Heavy => config.weapons.heavy.cost,
+            BubbleWeapon::Shotgun => config.weapons.shotgun.cost,
+            BubbleWeapon::Mine => config.weapons.mine.cost,
+            BubbleWeapon::Charge => {
+                config.weapons.charge.min_size * config.weapons.charge.cost_per_size
+            }
+        }
+    }
+
+    // Only bubbles whose damage grows with their size can merge without losing
+    // any of it
+    fn merges(self) -> bool {
+        matches!(self, BubbleWeapon::Standard | BubbleWeapon::Charge)
+    }
+
+    // Seconds between shots. The standard bubble fires every tick.
+    fn cooldown(self, config: &GameConfig) -> f32 {
+        match self {
+            BubbleWeapon::Standard | BubbleWeapon::Charge => 0.0,
+            BubbleWeapon::Sticky => config.weapons.sticky.cooldown,
+            BubbleWeapon::Heavy => config.weapons.heavy.cooldown,
+            BubbleWeapon::Shotgun => config.weapons.shotgun.cooldown,
+            BubbleWeapon::Mine => config.weapons.mine.cooldown,
+        }
+    }
+}
+
+// The ship's selected weapon and time until it can fire again
+#[derive(Component, Default)]
+pub struct Arsenal {
+    pub selected: BubbleWeapon,
+    cooldown: f32,
+}
+
+// Marks the bubble being inflated at the ship's nose. It has no collider
+// until it is launched.
+#[derive(Component)]
+struct Charging;
+
+// Add bubble shot event
+#[derive(Event)]
+pub struct BubbleShot;
+
+#[derive(Component)]
+struct WeaponText;
+
+pub struct BubblePlugin;
+
+impl Plugin for BubblePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<BubbleShot>()
+            .add_systems(Startup, spawn_weapon_ui)
+            .add_systems(OnEnter(GameState::Starting), clear_weapon_request)
+            .add_systems(
+                FixedUpdate,
+                (
+                    select_weapon.in_set(SimulationSet::Control),
+                    (
+                        (spawn_bubble, charge_bubble)
+                            .chain()
+                            .before(update_spawn_warnings),
+                        drift_mines,
+                    )
+                        .in_set(SimulationSet::Act),
+                    handle_bubble_collisions.in_set(CollisionSet),
+                    (
+                        confine_bubbles,
+                        update_bubble_lifetime
+                            .after(check_game_over)
+                            .before(drop_pickups),
+                    )
+                        .in_set(SimulationSet::Resolve),
+                )
+                    .in_set(GameSet::Simulation),
+            )
+            .add_systems(
+                Update,
+                (draw_bubbles, update_weapon_display).in_set(GameSet::Draw),
+            );
+    }
+}
+
+fn random_pastel_color(rng: &mut impl Rng) -> Color {
+    Color::hsl(
+        rng.gen_range(0.0..360.0), // Random hue
+        0.7,                       // High saturation
+        0.8,                       // High lightness for pastel
+    )
+}
+
+// A request made during the previous round shouldn't carry over
+fn clear_weapon_request(mut input: ResMut<PlayerInput>) {
+    input.weapon_slot = None;
+}
+
+fn select_weapon(mut query: Query<&mut Arsenal>, mut input: ResMut<PlayerInput>) {
+    let Some(slot) = input.weapon_slot.take() else {
+        return;
+    };
+    let Some(weapon) = BubbleWeapon::ALL.get(slot) else {
+        return;
+    };
+    if let Ok(mut arsenal) = query.get_single_mut() {
+        arsenal.selected = *weapon;
+    }
+}
+
+// Update bubble spawning to send event
+#[allow(clippy::type_complexity)]
+fn spawn_bubble(
+    mut commands: Commands,
+    mut ship_query: Query<(
+        &Transform,
+        &mut Ship,
+        &mut Velocity,
+        &mut Arsenal,
+        &ShootingState,
+        &AimControl,
+        &PowerUps,
+    )>,
+    mut bubble_shot: EventWriter<BubbleShot>,
+    mut game_rng: ResMut<GameRng>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let Ok((ship_transform, mut ship, mut ship_vel, mut arsenal, shooting, aim, power_ups)) =
+        ship_query.get_single_mut()
+    else {
+        return;
+    };
+
+    arsenal.cooldown = (arsenal.cooldown - time.delta_secs()).max(0.0);
+    let weapon = arsenal.selected;
+
+    // Handled by `charge_bubble`
+    if weapon == BubbleWeapon::Charge {
+        return;
+    }
+
+    let cost = weapon.cost(&config);
+    if !shooting.is_shooting || arsenal.cooldown > 0.0 || ship.bubble_supply < cost {
+        return;
+    }
+
+    ship.bubble_supply -= cost;
+    arsenal.cooldown = weapon.cooldown(&config);
+    let ship_pos = ship_transform.translation.truncate();
+    let rng = &mut game_rng.bubbles;
+
+    let spread_multiplier = if power_ups.is_active(PickupKind::WideSpread) {
+        config.pickups.spread_multiplier
+    } else {
+        1.0
+    };
+
+    // Rapid fire gets several shots for the cost of one
+    let shots = if power_ups.is_active(PickupKind::RapidFire) {
+        config.pickups.rapid_fire_shots.max(1)
+    } else {
+        1
+    };
+
+    for _ in 0..shots {
+        let recoil = fire_weapon(
+            &mut commands,
+            rng,
+            &config,
+            weapon,
+            ship_pos,
+            aim.angle,
+            spread_multiplier,
+        );
+
+        // Apply recoil to ship
+        ship_vel.0 -= recoil * config.ship.recoil_force;
+    }
+
+    bubble_shot.send(BubbleShot);
+}
+
+// Spawn the bubbles of one shot and return the summed firing directions, for
+// the recoil
+fn fire_weapon(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    config: &GameConfig,
+    weapon: BubbleWeapon,
+    pos: Vec2,
+    aim_angle: f32,
+    spread_multiplier: f32,
+) -> Vec2 {
+    let mut recoil = Vec2::ZERO;
+    let mut spawn = |angle: f32, speed: f32, color: Color, size: f32, lifetime: f32, mass: f32| {
+        let direction = Vec2::from_angle(angle);
+        recoil += direction;
+        commands.spawn((
+            Bubble {
+                weapon,
+                color,
+                size,
+                lifetime: Timer::from_seconds(lifetime, TimerMode::Once),
+            },
+            Transform::from_xyz(pos.x, pos.y, 0.0),
+            Velocity(direction * speed),
+            Collider {
+                radius: size,
+                mass,
+                layer: CollisionLayer::Bubble,
+            },
+            GameplayObject,
+        ));
+    };
+
+    let spread_angle = config.bubble.spread_angle * spread_multiplier;
+    match weapon {
+        BubbleWeapon::Standard => {
+            let bubble_config = &config.bubble;
+            let angle = aim_angle + rng.gen_range(-spread_angle..=spread_angle);
+            let speed = rng.gen_range(bubble_config.min_speed..=bubble_config.max_speed);
+            let color = random_pastel_color(rng);
+            let size = rng.gen_range(bubble_config.min_size..=bubble_config.max_size);
+            let lifetime = rng.gen_range(bubble_config.min_lifetime..=bubble_config.max_lifetime);
+            spawn(
+                angle,
+                speed,
+                color,
+                size,
+                lifetime,
+                size * bubble_config.mass_per_size,
+            );
+        }
+        BubbleWeapon::Sticky => {
+            let sticky = &config.weapons.sticky;
+            let angle = aim_angle + rng.gen_range(-spread_angle..=spread_angle);
+            spawn(
+                angle,
+                sticky.speed,
+                Color::srgb(0.6, 1.0, 0.3),
+                sticky.size,
+                sticky.lifetime,
+                sticky.mass,
+            );
+        }
+        BubbleWeapon::Heavy => {
+            let heavy = &config.weapons.heavy;
+            spawn(
+                aim_angle,
+                heavy.speed,
+                Color::srgb(0.5, 0.6, 1.0),
+                heavy.size,
+                heavy.lifetime,
+                heavy.mass,
+            );
+        }
+        BubbleWeapon::Shotgun => {
+            // Fan the pellets out evenly, with a little jitter
+            let shotgun = &config.weapons.shotgun;
+            let spread_angle = shotgun.spread_angle * spread_multiplier;
+            let pellets = shotgun.pellets.max(1);
+            for i in 0..pellets {
+                let t = if pellets > 1 {
+                    i as f32 / (pellets - 1) as f32 * 2.0 - 1.0
+                } else {
+                    0.0
+                };
+                let jitter = rng.gen_range(-0.05..=0.05);
+                let speed = rng.gen_range(shotgun.min_speed..=shotgun.max_speed);
+                let lifetime = rng.gen_range(shotgun.min_lifetime..=shotgun.max_lifetime);
+                spawn(
+                    aim_angle + t * spread_angle + jitter,
+                    speed,
+                    random_pastel_color(rng),
+                    shotgun.size,
+                    lifetime,
+                    shotgun.mass,
+                );
+            }
+        }
+        BubbleWeapon::Charge => {}
+        BubbleWeapon::Mine => {
+            let mine = &config.weapons.mine;
+            spawn(
+                aim_angle,
+                mine.speed,
+                Color::srgb(1.0, 0.4, 0.3),
+                mine.size,
+                mine.lifetime,
+                mine.mass,
+            );
+        }
+    }
+
+    recoil
+}
+
+// Hold fire to inflate one bubble at the ship's nose, using supply as it
+// grows, and release to launch it
+#[allow(clippy::type_complexity)]
+fn charge_bubble(
+    mut commands: Commands,
+    mut ship_query: Query<(
+        &Transform,
+        &mut Ship,
+        &mut Velocity,
+        &Arsenal,
+        &ShootingState,
+        &AimControl,
+    )>,
+    mut charging_query: Query<
+        (Entity, &mut Transform, &mut Velocity, &mut Bubble),
+        (With<Charging>, Without<Ship>),
+    >,
+    mut bubble_shot: EventWriter<BubbleShot>,
+    mut game_rng: ResMut<GameRng>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let Ok((ship_transform, mut ship, mut ship_vel, arsenal, shooting, aim)) =
+        ship_query.get_single_mut()
+    else {
+        return;
+    };
+
+    let charge = &config.weapons.charge;
+    let ship_pos = ship_transform.translation.truncate();
+    let direction = Vec2::from_angle(aim.angle);
+    let nose = |size: f32| ship_pos + direction * (config.ship.radius + size);
+    let holding = shooting.is_shooting && arsenal.selected == BubbleWeapon::Charge;
+
+    let Ok((entity, mut transform, mut velocity, mut bubble)) = charging_query.get_single_mut()
+    else {
+        let cost = BubbleWeapon::Charge.cost(&config);
+        if holding && ship.bubble_supply >= cost {
+            ship.bubble_supply -= cost;
+            let pos = nose(charge.min_size);
+            commands.spawn((
+                Bubble {
+                    weapon: BubbleWeapon::Charge,
+                    color: random_pastel_color(&mut game_rng.bubbles),
+                    size: charge.min_size,
+                    lifetime: Timer::from_seconds(charge.lifetime, TimerMode::Once),
+                },
+                Transform::from_xyz(pos.x, pos.y, 0.0),
+                Velocity(ship_vel.0),
+                Charging,
+                GameplayObject,
+            ));
+        }
+        return;
+    };
+
+    if holding {
+        // Grow as far as the supply allows, riding along with the ship
+        let growth = (charge.growth_rate * time.delta_secs())
+            .min(charge.max_size - bubble.size)
+            .min(ship.bubble_supply / charge.cost_per_size)
+            .max(0.0);
+        bubble.size += growth;
+        ship.bubble_supply -= growth * charge.cost_per_size;
+        transform.translation = nose(bubble.size).extend(0.0);
+        velocity.0 = ship_vel.0;
+        return;
+    }
+
+    // Launch on release, kicking the ship back by the bubble's size
+    velocity.0 = direction * charge.speed;
+    ship_vel.0 -= direction * charge.recoil_per_size * bubble.size;
+    commands
+        .entity(entity)
+        .remove::<Charging>()
+        .insert(Collider {
+            radius: bubble.size,
+            mass: bubble.size * charge.mass_per_size,
+            layer: CollisionLayer::Bubble,
+        });
+
+    bubble_shot.send(BubbleShot);
+}
+
+// Touching bubbles merge or bounce apart, depending on the config. Fresh
+// bubbles are left alone for a moment, or a stream would clump up at the nose.
+pub(crate) fn handle_bubble_collisions(
+    mut commands: Commands,
+    mut collisions: EventReader<BubbleBubbleCollision>,
+    mut bubbles: Query<(&mut Transform, &mut Velocity, &mut Collider, &mut Bubble)>,
+    config: Res<GameConfig>,
+) {
+    let bubble_config = &config.bubble;
+    let mut merged = EntityHashSet::default();
+
+    for collision in collisions.read() {
+        if merged.contains(&collision.a) || merged.contains(&collision.b) {
+            continue;
+        }
+        let Ok([a, b]) = bubbles.get_many_mut([collision.a, collision.b]) else {
+            continue;
+        };
+        let delay = bubble_config.interaction_delay;
+        if a.3.lifetime.elapsed_secs() < delay || b.3.lifetime.elapsed_secs() < delay {
+            continue;
+        }
+
+        match bubble_config.interaction {
+            BubbleInteraction::Ignore => {}
+            BubbleInteraction::Merge => {
+                if a.3.weapon != b.3.weapon || !a.3.weapon.merges() {
+                    continue;
+                }
+                if a.3.size + b.3.size > bubble_config.merge_max_size {
+                    continue;
+                }
+
+                // The bigger bubble swallows the smaller one
+                let (
+                    (mut transform, mut velocity, mut collider, mut bubble),
+                    (other_transform, other_velocity, other_collider, other_bubble),
+                    absorbed,
+                ) = if a.3.size >= b.3.size {
+                    (a, b, collision.b)
+                } else {
+                    (b, a, collision.a)
+                };
+
+                // Sizes add up and momentum carries over
+                let mass = collider.mass + other_collider.mass;
+                velocity.0 =
+                    (velocity.0 * collider.mass + other_velocity.0 * other_collider.mass) / mass;
+                transform.translation = (transform.translation * collider.mass
+                    + other_transform.translation * other_collider.mass)
+                    / mass;
+                bubble.size += other_bubble.size;
+                collider.radius = bubble.size;
+                collider.mass = mass;
+
+                // Live as long as whichever had more time left
+                if other_bubble.lifetime.remaining_secs() > bubble.lifetime.remaining_secs() {
+                    bubble.lifetime = other_bubble.lifetime.clone();
+                }
+
+                merged.insert(absorbed);
+                commands.entity(absorbed).despawn();
+            }
+            BubbleInteraction::Bounce => {
+                let (
+                    (mut transform_a, mut velocity_a, collider_a, _),
+                    (mut transform_b, mut velocity_b, collider_b, _),
+                ) = (a, b);
+                resolve_overlap(
+                    (&mut transform_a, &mut velocity_a, &collider_a),
+                    (&mut transform_b, &mut velocity_b, &collider_b),
+                    collision.normal,
+                    bubble_config.restitution,
+                );
+            }
+        }
+    }
+}
+
+fn draw_bubbles(
+    mut gizmos: Gizmos,
+    query: Query<(&Transform, &PreviousPosition, &Bubble, Has<Charging>)>,
+    time: Res<Time>,
+    fixed_time: Res<Time<Fixed>>,
+    config: Res<GameConfig>,
+) {
+    for (transform, previous, bubble, charging) in &query {
+        let pos = interpolated_position(transform, previous, &fixed_time);
+        let radius = bubble.size;
+        let elapsed = bubble.lifetime.elapsed_secs();
+
+        match bubble.weapon {
+            BubbleWeapon::Sticky => {
+                // Gooey blob with drips orbiting the rim
+                gizmos.circle_2d(pos, radius, bubble.color.with_alpha(0.8));
+                gizmos.circle_2d(pos, radius * 0.6, bubble.color.with_alpha(0.3));
+                for i in 0..3 {
+                    let angle = elapsed * 2.0 + i as f32 * std::f32::consts::TAU / 3.0;
+                    gizmos.circle_2d(
+                        pos + Vec2::from_angle(angle) * radius,
+                        radius * 0.3,
+                        bubble.color.with_alpha(0.6),
+                    );
+                }
+                continue;
+            }
+            BubbleWeapon::Heavy => {
+                // Thick shell
+                for i in 0..3 {
+                    gizmos.circle_2d(
+                        pos,
+                        radius - i as f32 * 2.0,
+                        bubble.color.with_alpha(0.9 - i as f32 * 0.25),
+                    );
+                }
+            }
+            BubbleWeapon::Mine => {
+                // Spiked shell, blinking faster as it runs out
+                let mine = &config.weapons.mine;
+                let remaining = bubble.lifetime.fraction_remaining();
+                let blink_rate = 2.0 + (1.0 - remaining) * 10.0;
+                let blink_on = ((elapsed * blink_rate) as u32).is_multiple_of(2);
+
+                gizmos.circle_2d(pos, radius, bubble.color.with_alpha(0.8));
+                for i in 0..8 {
+                    let direction = Vec2::from_angle(i as f32 * std::f32::consts::TAU / 8.0);
+                    gizmos.line_2d(
+                        pos + direction * radius,
+                        pos + direction * radius * 1.4,
+                        bubble.color.with_alpha(0.8),
+                    );
+                }
+                if blink_on {
+                    gizmos.circle_2d(pos, radius * 0.3, Color::WHITE.with_alpha(0.9));
+                }
+                gizmos.circle_2d(pos, mine.blast_radius, bubble.color.with_alpha(0.08));
+                continue;
+            }
+            BubbleWeapon::Charge => {
+                // Pulsing ring while it inflates
+                if charging {
+                    let pulse = (time.elapsed_secs() * 12.0).sin() * 0.5 + 0.5;
+                    gizmos.circle_2d(
+                        pos,
+                        radius + 3.0 + pulse * 3.0,
+                        Color::WHITE.with_alpha(0.2 + pulse * 0.3),
+                    );
+                }
+                gizmos.circle_2d(pos, radius + 2.0, bubble.color.with_alpha(0.2));
+                gizmos.circle_2d(pos, radius, bubble.color.with_alpha(0.8));
+            }
+            BubbleWeapon::Shotgun => {
+                // Small pellet with a streak trailing behind it
+                let direction = (transform.translation.truncate() - previous.0).normalize_or_zero();
+                gizmos.line_2d(
+                    pos - direction * radius,
+                    pos - direction * radius * 3.0,
+                    bubble.color.with_alpha(0.4),
+                );
+                gizmos.circle_2d(pos, radius, bubble.color.with_alpha(0.9));
+            }
+            BubbleWeapon::Standard => {
+                // Outer glow
+                gizmos.circle_2d(pos, radius + 2.0, bubble.color.with_alpha(0.2));
+
+                // Main bubble outline
+                gizmos.circle_2d(pos, radius, bubble.color.with_alpha(0.8));
+            }
+        }
+
+        // Inner highlight
+        gizmos.circle_2d(
+            pos + Vec2::new(-radius * 0.2, radius * 0.2),
+            radius * 0.4,
+            Color::WHITE.with_alpha(0.3),
+        );
+
+        // Shine detail
+        gizmos.circle_2d(
+            pos + Vec2::new(-radius * 0.1, radius * 0.1),
+            radius * 0.2,
+            Color::WHITE.with_alpha(0.5),
+        );
+    }
+}
+
+// Mines coast to a stop where they were dropped
+fn drift_mines(
+    mut query: Query<(&mut Velocity, &Bubble)>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let drag = drag_factor(config.weapons.mine.drag, time.delta_secs());
+    for (mut velocity, bubble) in &mut query {
+        if bubble.weapon == BubbleWeapon::Mine {
+            velocity.0 *= drag;
+        }
+    }
+}
+
+fn confine_bubbles(
+    mut query: Query<(&mut Transform, &mut Velocity), With<Bubble>>,
+    arena: Res<Arena>,
+    config: Res<GameConfig>,
+) {
+    for (mut transform, mut velocity) in &mut query {
+        arena.apply_topology(&config.border, &mut transform.translation, &mut velocity.0);
+    }
+}
+
+// A bubble still charging only starts to age once launched
+fn update_bubble_lifetime(
+    mut commands: Commands,
+    mut bubbles: Query<(Entity, &mut Bubble), Without<Charging>>,
+    time: Res<Time>,
+) {
+    for (entity, mut bubble) in &mut bubbles {
+        bubble.lifetime.tick(time.delta());
+        if bubble.lifetime.finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn spawn_weapon_ui(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(70.0),
+            ..default()
+        },
+        WeaponText,
+        Text::new(""),
+    ));
+}
+
+fn update_weapon_display(
+    arsenal_query: Query<&Arsenal>,
+    mut query: Query<&mut Text, With<WeaponText>>,
+) {
+    let (Ok(arsenal), Ok(mut text)) = (arsenal_query.get_single(), query.get_single_mut()) else {
+        return;
+    };
+    let slot = BubbleWeapon::ALL
+        .iter()
+        .position(|weapon| *weapon == arsenal.selected)
+        .unwrap_or_default();
+    text.0 = format!("[{}] {}", slot + 1, arsenal.selected.label());
+}
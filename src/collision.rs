@@ -0,0 +1,311 @@
+use bevy::prelude::*;
+
+use crate::game_flow::{GameSet, SimulationSet};
+use crate::physics::Velocity;
+use crate::spatial::SpatialGrid;
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CollisionLayer {
+    Ship,
+    Enemy,
+    Bubble,
+    Projectile,
+    Pickup,
+}
+
+#[derive(Component)]
+pub struct Collider {
+    pub radius: f32,
+    pub mass: f32,
+    pub layer: CollisionLayer,
+}
+
+// Ship touched an enemy. The normal points from the enemy towards the ship.
+#[derive(Event, Clone, Copy)]
+pub struct ShipEnemyCollision {
+    pub ship: Entity,
+    pub enemy: Entity,
+    pub normal: Vec2,
+}
+
+// Bubble touched an enemy. The normal points from the enemy towards the bubble.
+#[derive(Event, Clone, Copy)]
+pub struct BubbleEnemyCollision {
+    pub bubble: Entity,
+    pub enemy: Entity,
+    pub normal: Vec2,
+}
+
+// Two enemies touched. The normal points from `b` towards `a`.
+#[derive(Event, Clone, Copy)]
+pub struct EnemyEnemyCollision {
+    pub a: Entity,
+    pub b: Entity,
+    pub normal: Vec2,
+}
+
+// Two bubbles touched. The normal points from `b` towards `a`.
+#[derive(Event, Clone, Copy)]
+pub struct BubbleBubbleCollision {
+    pub a: Entity,
+    pub b: Entity,
+    pub normal: Vec2,
+}
+
+// Enemy projectile touched the ship. The normal points from the projectile
+// towards the ship.
+#[derive(Event, Clone, Copy)]
+pub struct ShipProjectileCollision {
+    pub ship: Entity,
+    pub projectile: Entity,
+    pub normal: Vec2,
+}
+
+// Bubble touched an enemy projectile. The normal points from the projectile
+// towards the bubble.
+#[derive(Event, Clone, Copy)]
+pub struct BubbleProjectileCollision {
+    pub bubble: Entity,
+    pub projectile: Entity,
+    pub normal: Vec2,
+}
+
+// Ship touched a pickup. The normal points from the pickup towards the ship.
+#[derive(Event, Clone, Copy)]
+pub struct ShipPickupCollision {
+    pub ship: Entity,
+    pub pickup: Entity,
+    pub normal: Vec2,
+}
+
+// Systems that respond to collision events. Runs after the collision pass for
+// the tick.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CollisionSet;
+
+pub struct CollisionPlugin;
+
+impl Plugin for CollisionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpatialGrid>()
+            .add_event::<ShipEnemyCollision>()
+            .add_event::<BubbleEnemyCollision>()
+            .add_event::<EnemyEnemyCollision>()
+            .add_event::<BubbleBubbleCollision>()
+            .add_event::<ShipProjectileCollision>()
+            .add_event::<BubbleProjectileCollision>()
+            .add_event::<ShipPickupCollision>()
+            .configure_sets(
+                FixedUpdate,
+                CollisionSet
+                    .in_set(GameSet::Simulation)
+                    .after(SimulationSet::Move)
+                    .before(SimulationSet::Resolve),
+            )
+            .add_systems(
+                FixedUpdate,
+                (rebuild_spatial_grid, detect_collisions)
+                    .chain()
+                    .in_set(GameSet::Simulation)
+                    .after(SimulationSet::Move)
+                    .before(CollisionSet),
+            );
+    }
+}
+
+// Velocity change for two bodies bouncing off each other along `normal`
+// (pointing from `b` towards `a`). `a` gains the returned impulse divided by
+// its mass and `b` loses it divided by its own. Restitution of 1.0 is a fully
+// elastic bounce.
+pub fn collision_impulse(
+    (velocity_a, mass_a): (Vec2, f32),
+    (velocity_b, mass_b): (Vec2, f32),
+    normal: Vec2,
+    restitution: f32,
+) -> Vec2 {
+    let approach_speed = (velocity_a - velocity_b).dot(normal);
+    if approach_speed >= 0.0 {
+        return Vec2::ZERO; // Already separating
+    }
+    normal * -(1.0 + restitution) * approach_speed / (1.0 / mass_a + 1.0 / mass_b)
+}
+
+// Push two overlapping bodies apart along `normal` (pointing from `b` towards
+// `a`), the lighter one giving way more, then bounce them off each other
+pub fn resolve_overlap(
+    (transform_a, velocity_a, collider_a): (&mut Transform, &mut Velocity, &Collider),
+    (transform_b, velocity_b, collider_b): (&mut Transform, &mut Velocity, &Collider),
+    normal: Vec2,
+    restitution: f32,
+) {
+    let inverse_mass_a = 1.0 / collider_a.mass;
+    let inverse_mass_b = 1.0 / collider_b.mass;
+    let inverse_mass_sum = inverse_mass_a + inverse_mass_b;
+
+    let distance = transform_a
+        .translation
+        .truncate()
+        .distance(transform_b.translation.truncate());
+    let overlap = collider_a.radius + collider_b.radius - distance;
+    if overlap > 0.0 {
+        let correction = normal * overlap / inverse_mass_sum;
+        transform_a.translation += (correction * inverse_mass_a).extend(0.0);
+        transform_b.translation -= (correction * inverse_mass_b).extend(0.0);
+    }
+
+    let impulse = collision_impulse(
+        (velocity_a.0, collider_a.mass),
+        (velocity_b.0, collider_b.mass),
+        normal,
+        restitution,
+    );
+    velocity_a.0 += impulse * inverse_mass_a;
+    velocity_b.0 -= impulse * inverse_mass_b;
+}
+
+// Apply an impulse between two bodies, `a` gaining and `b` losing momentum
+pub fn exchange_momentum(
+    velocities: &mut Query<(&mut Velocity, &Collider)>,
+    a: Entity,
+    b: Entity,
+    impulse: Vec2,
+) {
+    if let Ok([(mut velocity_a, collider_a), (mut velocity_b, collider_b)]) =
+        velocities.get_many_mut([a, b])
+    {
+        velocity_a.0 += impulse / collider_a.mass;
+        velocity_b.0 -= impulse / collider_b.mass;
+    }
+}
+
+fn rebuild_spatial_grid(
+    mut grid: ResMut<SpatialGrid>,
+    query: Query<(Entity, &Transform, &Collider)>,
+) {
+    grid.clear();
+    for (entity, transform, collider) in &query {
+        grid.insert(entity, transform.translation.truncate(), collider.radius);
+    }
+}
+
+// Find every overlapping pair once and report the ones gameplay cares about
+#[allow(clippy::too_many_arguments)]
+fn detect_collisions(
+    grid: Res<SpatialGrid>,
+    colliders: Query<(Entity, &Transform, &Collider)>,
+    mut ship_enemy: EventWriter<ShipEnemyCollision>,
+    mut bubble_enemy: EventWriter<BubbleEnemyCollision>,
+    mut enemy_enemy: EventWriter<EnemyEnemyCollision>,
+    mut bubble_bubble: EventWriter<BubbleBubbleCollision>,
+    mut ship_projectile: EventWriter<ShipProjectileCollision>,
+    mut bubble_projectile: EventWriter<BubbleProjectileCollision>,
+    mut ship_pickup: EventWriter<ShipPickupCollision>,
+) {
+    for (entity, transform, collider) in &colliders {
+        let pos = transform.translation.truncate();
+
+        for (other, other_pos) in grid.query(pos, collider.radius + grid.max_radius()) {
+            // Each pair is seen from both sides, only handle it once
+            if other <= entity {
+                continue;
+            }
+            let Ok((_, _, other_collider)) = colliders.get(other) else {
+                continue;
+            };
+            if pos.distance(other_pos) >= collider.radius + other_collider.radius {
+                continue;
+            }
+
+            // Points from `other` towards `entity`
+            let normal = (pos - other_pos).normalize_or(Vec2::Y);
+
+            match (collider.layer, other_collider.layer) {
+                (CollisionLayer::Ship, CollisionLayer::Enemy) => {
+                    ship_enemy.send(ShipEnemyCollision {
+                        ship: entity,
+                        enemy: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Enemy, CollisionLayer::Ship) => {
+                    ship_enemy.send(ShipEnemyCollision {
+                        ship: other,
+                        enemy: entity,
+                        normal: -normal,
+                    });
+                }
+                (CollisionLayer::Bubble, CollisionLayer::Enemy) => {
+                    bubble_enemy.send(BubbleEnemyCollision {
+                        bubble: entity,
+                        enemy: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Enemy, CollisionLayer::Bubble) => {
+                    bubble_enemy.send(BubbleEnemyCollision {
+                        bubble: other,
+                        enemy: entity,
+                        normal: -normal,
+                    });
+                }
+                (CollisionLayer::Enemy, CollisionLayer::Enemy) => {
+                    enemy_enemy.send(EnemyEnemyCollision {
+                        a: entity,
+                        b: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Bubble, CollisionLayer::Bubble) => {
+                    bubble_bubble.send(BubbleBubbleCollision {
+                        a: entity,
+                        b: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Ship, CollisionLayer::Projectile) => {
+                    ship_projectile.send(ShipProjectileCollision {
+                        ship: entity,
+                        projectile: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Projectile, CollisionLayer::Ship) => {
+                    ship_projectile.send(ShipProjectileCollision {
+                        ship: other,
+                        projectile: entity,
+                        normal: -normal,
+                    });
+                }
+                (CollisionLayer::Bubble, CollisionLayer::Projectile) => {
+                    bubble_projectile.send(BubbleProjectileCollision {
+                        bubble: entity,
+                        projectile: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Projectile, CollisionLayer::Bubble) => {
+                    bubble_projectile.send(BubbleProjectileCollision {
+                        bubble: other,
+                        projectile: entity,
+                        normal: -normal,
+                    });
+                }
+                (CollisionLayer::Ship, CollisionLayer::Pickup) => {
+                    ship_pickup.send(ShipPickupCollision {
+                        ship: entity,
+                        pickup: other,
+                        normal,
+                    });
+                }
+                (CollisionLayer::Pickup, CollisionLayer::Ship) => {
+                    ship_pickup.send(ShipPickupCollision {
+                        ship: other,
+                        pickup: entity,
+                        normal: -normal,
+                    });
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -0,0 +1,957 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::arena::Topology;
+use crate::bubble::BubbleInteraction;
+use crate::enemy::floater::FLOATER;
+use crate::enemy::seeker::SEEKER;
+use crate::enemy::shooter::SHOOTER;
+use crate::enemy::splitter::{SHARD, SPLITTER};
+use crate::enemy::EnemyKind;
+use crate::pickup::PickupKind;
+
+const GAME_CONFIG_PATH: &str = "balance.config.ron";
+
+// Game balance values. Loaded from `assets/balance.config.ron` when an asset
+// server is available and reloaded whenever the file changes (build with the
+// `hot_reload` feature). Missing fields fall back to the defaults below.
+#[derive(Asset, Resource, TypePath, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct GameConfig {
+    pub ship: ShipConfig,
+    pub bubble: BubbleConfig,
+    pub weapons: WeaponsConfig,
+    pub enemy: EnemyConfig,
+    pub enemies: BTreeMap<EnemyKind, EnemyKindConfig>,
+    pub gun: GunConfig,
+    pub boss: BossConfig,
+    pub waves: WavesConfig,
+    pub border: BorderConfig,
+    pub pickups: PickupConfig,
+    pub trap: TrapConfig,
+    pub ship_explosion: ExplosionConfig,
+    pub enemy_explosion: ExplosionConfig,
+    pub boss_explosion: ExplosionConfig,
+    pub score: ScoreConfig,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            ship: default(),
+            bubble: default(),
+            weapons: default(),
+            enemy: default(),
+            enemies: default_enemy_kinds(),
+            gun: default(),
+            boss: default(),
+            waves: default(),
+            border: default(),
+            pickups: default(),
+            trap: default(),
+            ship_explosion: default(),
+            enemy_explosion: ExplosionConfig {
+                particles: 15,
+                min_speed: 50.0,
+                max_speed: 150.0,
+                min_size: 1.0,
+                max_size: 4.0,
+                lifetime: 0.5,
+                drag: 0.3,
+            },
+            boss_explosion: ExplosionConfig {
+                particles: 120,
+                min_speed: 80.0,
+                max_speed: 450.0,
+                min_size: 3.0,
+                max_size: 14.0,
+                lifetime: 2.0,
+                drag: 0.16,
+            },
+            score: default(),
+        }
+    }
+}
+
+// Used for kinds the config doesn't list
+static DEFAULT_ENEMY_KIND: EnemyKindConfig = EnemyKindConfig {
+    health: 100.0,
+    radius: 20.0,
+    mass: 2.0,
+    speed: 50.0,
+    spawn_weight: 0.0,
+    turn_rate: 1.5,
+    min_children: 2,
+    max_children: 3,
+};
+
+impl GameConfig {
+    pub fn enemy_kind(&self, kind: &EnemyKind) -> &EnemyKindConfig {
+        self.enemies.get(kind).unwrap_or(&DEFAULT_ENEMY_KIND)
+    }
+
+    // Fix values the game can't use, e.g. a min above its max, which would
+    // panic when picking a random value between them
+    pub fn validate(&mut self) {
+        // Timers panic on negative durations
+        for (name, seconds) in [
+            (
+                "ship.invulnerability_time",
+                &mut self.ship.invulnerability_time,
+            ),
+            ("bubble.min_lifetime", &mut self.bubble.min_lifetime),
+            ("bubble.max_lifetime", &mut self.bubble.max_lifetime),
+            ("weapons.sticky.lifetime", &mut self.weapons.sticky.lifetime),
+            (
+                "weapons.sticky.slow_time",
+                &mut self.weapons.sticky.slow_time,
+            ),
+            ("weapons.heavy.lifetime", &mut self.weapons.heavy.lifetime),
+            (
+                "weapons.shotgun.min_lifetime",
+                &mut self.weapons.shotgun.min_lifetime,
+            ),
+            (
+                "weapons.shotgun.max_lifetime",
+                &mut self.weapons.shotgun.max_lifetime,
+            ),
+            ("weapons.mine.lifetime", &mut self.weapons.mine.lifetime),
+            ("weapons.charge.lifetime", &mut self.weapons.charge.lifetime),
+            ("enemy.growth_time", &mut self.enemy.growth_time),
+            (
+                "enemy.spawn_warning_time",
+                &mut self.enemy.spawn_warning_time,
+            ),
+            ("gun.fire_interval", &mut self.gun.fire_interval),
+            ("gun.projectile_lifetime", &mut self.gun.projectile_lifetime),
+            ("boss.volley_interval", &mut self.boss.volley_interval),
+            ("boss.burst_interval", &mut self.boss.burst_interval),
+            ("boss.charge_interval", &mut self.boss.charge_interval),
+            ("waves.start_delay", &mut self.waves.start_delay),
+            ("pickups.lifetime", &mut self.pickups.lifetime),
+            ("pickups.duration", &mut self.pickups.duration),
+            ("trap.duration", &mut self.trap.duration),
+            ("ship_explosion.lifetime", &mut self.ship_explosion.lifetime),
+            (
+                "enemy_explosion.lifetime",
+                &mut self.enemy_explosion.lifetime,
+            ),
+            ("boss_explosion.lifetime", &mut self.boss_explosion.lifetime),
+        ] {
+            fix_negative(name, seconds);
+        }
+        for (index, wave) in self.waves.list.iter_mut().enumerate() {
+            fix_negative(&format!("waves.list[{index}].breather"), &mut wave.breather);
+            for (group_index, group) in wave.groups.iter_mut().enumerate() {
+                fix_negative(
+                    &format!("waves.list[{index}].groups[{group_index}].interval"),
+                    &mut group.interval,
+                );
+            }
+        }
+
+        // Picking a random value between a min and a max panics if they are
+        // the wrong way round
+        let bubble = &mut self.bubble;
+        fix_range(
+            "bubble.*_speed",
+            &mut bubble.min_speed,
+            &mut bubble.max_speed,
+        );
+        fix_range("bubble.*_size", &mut bubble.min_size, &mut bubble.max_size);
+        fix_range(
+            "bubble.*_lifetime",
+            &mut bubble.min_lifetime,
+            &mut bubble.max_lifetime,
+        );
+        fix_negative("bubble.spread_angle", &mut bubble.spread_angle);
+
+        let shotgun = &mut self.weapons.shotgun;
+        fix_range(
+            "weapons.shotgun.*_speed",
+            &mut shotgun.min_speed,
+            &mut shotgun.max_speed,
+        );
+        fix_range(
+            "weapons.shotgun.*_lifetime",
+            &mut shotgun.min_lifetime,
+            &mut shotgun.max_lifetime,
+        );
+        fix_negative("weapons.shotgun.spread_angle", &mut shotgun.spread_angle);
+
+        let charge = &mut self.weapons.charge;
+        fix_range(
+            "weapons.charge.*_size",
+            &mut charge.min_size,
+            &mut charge.max_size,
+        );
+
+        for (name, explosion) in [
+            ("ship_explosion", &mut self.ship_explosion),
+            ("enemy_explosion", &mut self.enemy_explosion),
+            ("boss_explosion", &mut self.boss_explosion),
+        ] {
+            fix_range(
+                &format!("{name}.*_speed"),
+                &mut explosion.min_speed,
+                &mut explosion.max_speed,
+            );
+            fix_range(
+                &format!("{name}.*_size"),
+                &mut explosion.min_size,
+                &mut explosion.max_size,
+            );
+        }
+
+        fix_negative("enemy.cluster_spread", &mut self.enemy.cluster_spread);
+        for (kind, kind_config) in &mut self.enemies {
+            fix_negative(&format!("enemies.{kind}.speed"), &mut kind_config.speed);
+            fix_range(
+                &format!("enemies.{kind}.*_children"),
+                &mut kind_config.min_children,
+                &mut kind_config.max_children,
+            );
+        }
+    }
+}
+
+fn fix_range<T: PartialOrd + fmt::Display>(name: &str, min: &mut T, max: &mut T) {
+    if *min > *max {
+        warn!("{name}: min {min} is above max {max}, swapping them");
+        std::mem::swap(min, max);
+    }
+}
+
+fn fix_negative(name: &str, value: &mut f32) {
+    if *value < 0.0 {
+        warn!("{name}: {value} can't be negative, using {}", -*value);
+        *value = -*value;
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ShipConfig {
+    pub health: f32,
+    pub radius: f32,
+    pub mass: f32,
+    pub acceleration: f32,
+    pub max_speed: f32,
+    pub friction: f32, // Fraction of velocity kept per second
+    pub recoil_force: f32,
+    pub invulnerability_time: f32, // Seconds of protection after taking damage
+}
+
+impl Default for ShipConfig {
+    fn default() -> Self {
+        Self {
+            health: 100.0,
+            radius: 15.0,
+            mass: 1.0,
+            acceleration: 1000.0,
+            max_speed: 300.0,
+            friction: 0.3,
+            recoil_force: 5.0,
+            invulnerability_time: 1.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BubbleConfig {
+    pub max_supply: f32,
+    pub cost: f32,
+    pub regen_rate: f32, // Per second
+    pub min_size: f32,
+    pub max_size: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub min_lifetime: f32,
+    pub max_lifetime: f32,
+    pub spread_angle: f32,
+    pub damage_per_size: f32, // Damage is bubble size times this
+    pub mass_per_size: f32,   // Momentum handed to enemies on a hit
+    pub interaction: BubbleInteraction,
+    pub interaction_delay: f32, // Seconds before a new bubble touches others
+    pub merge_max_size: f32,    // Bubbles that would grow past this don't merge
+    pub restitution: f32,       // Bounciness when bubbles bounce apart
+}
+
+impl Default for BubbleConfig {
+    fn default() -> Self {
+        Self {
+            max_supply: 100.0,
+            cost: 1.0,
+            regen_rate: 50.0,
+            min_size: 5.0,
+            max_size: 15.0,
+            min_speed: 100.0,
+            max_speed: 200.0,
+            min_lifetime: 1.0,
+            max_lifetime: 2.0,
+            spread_angle: 0.3,
+            damage_per_size: 1.0,
+            mass_per_size: 0.002,
+            interaction: BubbleInteraction::Ignore,
+            interaction_delay: 0.2,
+            merge_max_size: 45.0,
+            restitution: 0.9,
+        }
+    }
+}
+
+// Alternate bubble weapons. The standard bubble uses `BubbleConfig`.
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct WeaponsConfig {
+    pub sticky: StickyBubbleConfig,
+    pub heavy: HeavyBubbleConfig,
+    pub shotgun: ShotgunBubbleConfig,
+    pub mine: MineBubbleConfig,
+    pub charge: ChargeBubbleConfig,
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct StickyBubbleConfig {
+    pub cost: f32,
+    pub cooldown: f32, // Seconds between shots
+    pub size: f32,
+    pub speed: f32,
+    pub lifetime: f32,
+    pub damage: f32,
+    pub mass: f32,
+    pub slow_factor: f32, // Enemy speed multiplier while slowed
+    pub slow_time: f32,
+}
+
+impl Default for StickyBubbleConfig {
+    fn default() -> Self {
+        Self {
+            cost: 2.0,
+            cooldown: 0.1,
+            size: 8.0,
+            speed: 180.0,
+            lifetime: 2.0,
+            damage: 6.0,
+            mass: 0.005,
+            slow_factor: 0.4,
+            slow_time: 2.5,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct HeavyBubbleConfig {
+    pub cost: f32,
+    pub cooldown: f32,
+    pub size: f32,
+    pub speed: f32,
+    pub lifetime: f32,
+    pub damage: f32,
+    pub mass: f32, // Knockback handed to the enemy on a hit
+}
+
+impl Default for HeavyBubbleConfig {
+    fn default() -> Self {
+        Self {
+            cost: 8.0,
+            cooldown: 0.35,
+            size: 22.0,
+            speed: 160.0,
+            lifetime: 2.5,
+            damage: 45.0,
+            mass: 1.5,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ShotgunBubbleConfig {
+    pub cost: f32,
+    pub cooldown: f32,
+    pub pellets: u32,
+    pub spread_angle: f32, // Pellets fan out evenly across twice this
+    pub size: f32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub min_lifetime: f32,
+    pub max_lifetime: f32,
+    pub damage: f32, // Per pellet
+    pub mass: f32,
+}
+
+impl Default for ShotgunBubbleConfig {
+    fn default() -> Self {
+        Self {
+            cost: 10.0,
+            cooldown: 0.4,
+            pellets: 7,
+            spread_angle: 0.5,
+            size: 6.0,
+            min_speed: 220.0,
+            max_speed: 320.0,
+            min_lifetime: 0.4,
+            max_lifetime: 0.7,
+            damage: 12.0,
+            mass: 0.01,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct MineBubbleConfig {
+    pub cost: f32,
+    pub cooldown: f32,
+    pub size: f32,
+    pub speed: f32,
+    pub drag: f32, // Fraction of velocity kept per second
+    pub lifetime: f32,
+    pub mass: f32,
+    pub damage: f32, // Dealt to every enemy in the blast
+    pub blast_radius: f32,
+    pub blast_force: f32,
+}
+
+impl Default for MineBubbleConfig {
+    fn default() -> Self {
+        Self {
+            cost: 15.0,
+            cooldown: 0.6,
+            size: 12.0,
+            speed: 80.0,
+            drag: 0.16,
+            lifetime: 10.0,
+            mass: 0.024,
+            damage: 80.0,
+            blast_radius: 90.0,
+            blast_force: 300.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ChargeBubbleConfig {
+    pub min_size: f32, // Size when charging starts
+    pub max_size: f32,
+    pub growth_rate: f32,   // Size gained per second of holding fire
+    pub cost_per_size: f32, // Supply used per unit of size
+    pub speed: f32,
+    pub lifetime: f32,
+    pub damage_per_size: f32,
+    pub mass_per_size: f32,
+    pub recoil_per_size: f32,
+}
+
+impl Default for ChargeBubbleConfig {
+    fn default() -> Self {
+        Self {
+            min_size: 6.0,
+            max_size: 60.0,
+            growth_rate: 30.0,
+            cost_per_size: 3.0,
+            speed: 150.0,
+            lifetime: 3.0,
+            damage_per_size: 2.0,
+            mass_per_size: 0.02,
+            recoil_per_size: 0.6,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct EnemyConfig {
+    pub speed_increase_per_bounce: f32, // Up to the kind's speed
+    pub collision_damage: f32,
+    pub collision_force: f32, // Extra push on the ship, felt by the enemy too
+    pub restitution: f32,     // Bounciness of collisions, 1.0 is fully elastic
+    pub growth_time: f32,
+    pub min_scale: f32,
+    pub spawn_margin: f32,       // Keep enemies away from borders on spawn
+    pub min_spawn_distance: f32, // Keep enemies away from the ship on spawn
+    pub cluster_spread: f32,     // Furthest offset from the center of a Cluster group
+    pub spawn_warning_time: f32, // Seconds a marker shows before an enemy appears
+    pub speed_scale_time: f32,   // Seconds to reach max speed
+    pub max_speed_multiplier: f32,
+}
+
+impl Default for EnemyConfig {
+    fn default() -> Self {
+        Self {
+            speed_increase_per_bounce: 50.0,
+            collision_damage: 20.0,
+            collision_force: 400.0,
+            restitution: 0.8,
+            growth_time: 1.0,
+            min_scale: 0.1,
+            spawn_margin: 100.0,
+            min_spawn_distance: 250.0,
+            cluster_spread: 60.0,
+            spawn_warning_time: 1.0,
+            speed_scale_time: 60.0,
+            max_speed_multiplier: 3.0,
+        }
+    }
+}
+
+// What sets one enemy kind apart, keyed by kind in `GameConfig::enemies`.
+// Fields only some kinds use are ignored by the rest. Unknown fields are an
+// error, so a typo can't silently fall back to a default.
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct EnemyKindConfig {
+    pub health: f32,
+    pub radius: f32,
+    pub mass: f32,
+    pub speed: f32,
+    pub spawn_weight: f32, // Chance of being picked by groups that don't name a kind
+    pub turn_rate: f32,    // Radians per second, for kinds that home in on the ship
+    pub min_children: u32, // Released when popped, for kinds that split
+    pub max_children: u32,
+}
+
+impl Default for EnemyKindConfig {
+    fn default() -> Self {
+        DEFAULT_ENEMY_KIND.clone()
+    }
+}
+
+fn default_enemy_kinds() -> BTreeMap<EnemyKind, EnemyKindConfig> {
+    BTreeMap::from([
+        (
+            FLOATER,
+            EnemyKindConfig {
+                spawn_weight: 4.0,
+                ..default()
+            },
+        ),
+        (
+            SEEKER,
+            EnemyKindConfig {
+                health: 60.0,
+                speed: 120.0,
+                spawn_weight: 2.0,
+                turn_rate: 1.5,
+                ..default()
+            },
+        ),
+        (
+            SPLITTER,
+            EnemyKindConfig {
+                health: 150.0,
+                radius: 26.0,
+                mass: 3.0,
+                speed: 60.0,
+                spawn_weight: 1.0,
+                min_children: 2,
+                max_children: 3,
+                ..default()
+            },
+        ),
+        (
+            SHARD,
+            EnemyKindConfig {
+                health: 30.0,
+                radius: 12.0,
+                mass: 1.0,
+                speed: 160.0,
+                ..default()
+            },
+        ),
+        (
+            SHOOTER,
+            EnemyKindConfig {
+                health: 80.0,
+                speed: 40.0,
+                spawn_weight: 1.0,
+                ..default()
+            },
+        ),
+    ])
+}
+
+// Guns mounted on enemies, e.g. Shooters
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct GunConfig {
+    pub fire_interval: f32, // Seconds between shots
+    pub projectile_speed: f32,
+    pub projectile_radius: f32,
+    pub projectile_mass: f32,
+    pub projectile_damage: f32,
+    pub projectile_lifetime: f32,
+}
+
+impl Default for GunConfig {
+    fn default() -> Self {
+        Self {
+            fire_interval: 2.5,
+            projectile_speed: 150.0,
+            projectile_radius: 6.0,
+            projectile_mass: 0.5,
+            projectile_damage: 15.0,
+            projectile_lifetime: 5.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BossConfig {
+    pub health: f32,
+    pub radius: f32,
+    pub mass: f32,
+    pub speed: f32,
+    pub turn_rate: f32,      // Radians per second the armor turns toward the ship
+    pub weak_point_arc: f32, // Radians of unarmored back that takes damage
+    pub burst_health: f32,   // Health fraction where the burst phase starts
+    pub charge_health: f32,  // Health fraction where the charge phase starts
+    pub volley_interval: f32,
+    pub volley_count: u32,
+    pub volley_spread: f32, // Radians between the outermost shots
+    pub burst_interval: f32,
+    pub burst_count: u32,
+    pub charge_interval: f32,
+    pub charge_speed: f32,
+    pub charge_drag: f32, // Fraction of velocity kept per second until back to normal speed
+}
+
+impl Default for BossConfig {
+    fn default() -> Self {
+        Self {
+            health: 1200.0,
+            radius: 60.0,
+            mass: 20.0,
+            speed: 40.0,
+            turn_rate: 0.8,
+            weak_point_arc: 1.6,
+            burst_health: 0.66,
+            charge_health: 0.33,
+            volley_interval: 2.0,
+            volley_count: 5,
+            volley_spread: 0.8,
+            burst_interval: 3.0,
+            burst_count: 16,
+            charge_interval: 3.5,
+            charge_speed: 400.0,
+            charge_drag: 0.16,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct WavesConfig {
+    pub start_delay: f32, // Seconds before the first wave
+    // A boss closes every wave that is a multiple of this. 0 disables bosses.
+    pub boss_every: u32,
+    // Once the list runs out the last wave repeats, with enemy counts growing
+    // by this fraction on every repeat
+    pub repeat_count_growth: f32,
+    pub list: Vec<WaveConfig>,
+}
+
+impl Default for WavesConfig {
+    fn default() -> Self {
+        use SpawnPattern::*;
+
+        let group = |kind, count, pattern, interval| WaveGroup {
+            kind: Some(kind),
+            count,
+            pattern,
+            interval,
+        };
+        let mixed = |count, pattern, interval| WaveGroup {
+            kind: None,
+            count,
+            pattern,
+            interval,
+        };
+
+        Self {
+            start_delay: 2.0,
+            boss_every: 5,
+            repeat_count_growth: 0.5,
+            list: vec![
+                WaveConfig {
+                    groups: vec![group(FLOATER, 4, Random, 2.0)],
+                    breather: 4.0,
+                },
+                WaveConfig {
+                    groups: vec![group(FLOATER, 6, Random, 1.5), group(FLOATER, 4, Ring, 2.0)],
+                    breather: 4.0,
+                },
+                WaveConfig {
+                    groups: vec![
+                        group(FLOATER, 8, Random, 1.0),
+                        group(SEEKER, 2, Random, 2.0),
+                        group(SPLITTER, 1, Random, 2.0),
+                    ],
+                    breather: 5.0,
+                },
+                WaveConfig {
+                    groups: vec![
+                        group(FLOATER, 6, Ring, 3.0),
+                        group(SEEKER, 4, Cluster, 2.0),
+                        group(SPLITTER, 2, Random, 1.5),
+                        group(SHOOTER, 1, Random, 2.0),
+                        group(FLOATER, 10, Random, 0.6),
+                    ],
+                    breather: 5.0,
+                },
+                WaveConfig {
+                    groups: vec![
+                        group(SEEKER, 6, Random, 0.8),
+                        group(SPLITTER, 3, Ring, 2.0),
+                        group(SHOOTER, 2, Random, 2.0),
+                        group(FLOATER, 8, Ring, 2.0),
+                        mixed(12, Random, 0.5),
+                    ],
+                    breather: 6.0,
+                },
+            ],
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct WaveConfig {
+    pub groups: Vec<WaveGroup>, // Spawned one after another
+    pub breather: f32,          // Seconds of rest after the last spawn
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct WaveGroup {
+    pub kind: Option<EnemyKind>, // Picked by spawn weight when missing
+    pub count: u32,
+    pub pattern: SpawnPattern,
+    pub interval: f32, // Seconds between spawns, or after a formation
+}
+
+impl Default for WaveGroup {
+    fn default() -> Self {
+        Self {
+            kind: None,
+            count: 1,
+            pattern: SpawnPattern::Random,
+            interval: 1.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq)]
+pub enum SpawnPattern {
+    Random,  // One at a time anywhere in the arena
+    Ring,    // The whole group at once on a circle around the center
+    Cluster, // The whole group at once, bunched around a random point
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BorderConfig {
+    pub topology: Topology,
+    pub width: f32,
+    pub damage: f32,
+    pub bounce_force: f32,
+}
+
+impl Default for BorderConfig {
+    fn default() -> Self {
+        Self {
+            topology: Topology::Hazard,
+            width: 50.0,
+            damage: 10.0,
+            bounce_force: 500.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct PickupConfig {
+    pub drop_chance: f32, // Per destroyed enemy. Bosses always drop one.
+    pub radius: f32,
+    pub lifetime: f32,         // Seconds before an uncollected pickup vanishes
+    pub duration: f32,         // Seconds a power-up lasts
+    pub rapid_fire_shots: u32, // Bubbles per shot, for the cost of one
+    pub spread_multiplier: f32,
+    pub regen_multiplier: f32,
+    pub repair_amount: f32,
+    pub weights: Vec<(PickupKind, f32)>, // Relative chance of each kind
+}
+
+impl Default for PickupConfig {
+    fn default() -> Self {
+        Self {
+            drop_chance: 0.12,
+            radius: 12.0,
+            lifetime: 8.0,
+            duration: 8.0,
+            rapid_fire_shots: 2,
+            spread_multiplier: 2.5,
+            regen_multiplier: 3.0,
+            repair_amount: 30.0,
+            weights: vec![
+                (PickupKind::RapidFire, 1.0),
+                (PickupKind::WideSpread, 1.0),
+                (PickupKind::FastRegen, 1.0),
+                (PickupKind::Shield, 0.7),
+                (PickupKind::Repair, 0.7),
+            ],
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct TrapConfig {
+    pub weak_health: f32, // Fraction of full health below which hits count
+    pub hits_to_trap: u32,
+    pub duration: f32,
+    pub drift_speed: f32,  // Speed of a freshly trapped enemy
+    pub drag: f32,         // Fraction of velocity kept per second while faster than drift
+    pub impact_speed: f32, // Relative speed needed to burst into another enemy
+    pub impact_damage: f32,
+    pub pop_multiplier: f32, // Score for popping a trapped enemy, times its points
+}
+
+impl Default for TrapConfig {
+    fn default() -> Self {
+        Self {
+            weak_health: 0.35,
+            hits_to_trap: 4,
+            duration: 5.0,
+            drift_speed: 20.0,
+            drag: 0.55,
+            impact_speed: 120.0,
+            impact_damage: 60.0,
+            pop_multiplier: 2.0,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ExplosionConfig {
+    pub particles: u32,
+    pub min_speed: f32,
+    pub max_speed: f32,
+    pub min_size: f32,
+    pub max_size: f32,
+    pub lifetime: f32,
+    pub drag: f32, // Fraction of velocity kept per second
+}
+
+impl Default for ExplosionConfig {
+    fn default() -> Self {
+        Self {
+            particles: 30,
+            min_speed: 100.0,
+            max_speed: 300.0,
+            min_size: 2.0,
+            max_size: 8.0,
+            lifetime: 1.0,
+            drag: 0.3,
+        }
+    }
+}
+
+#[derive(Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct ScoreConfig {
+    pub points_per_second: f32,
+    pub points_per_enemy: f32,
+    pub points_per_boss: f32,
+}
+
+impl Default for ScoreConfig {
+    fn default() -> Self {
+        Self {
+            points_per_second: 10.0,
+            points_per_enemy: 100.0,
+            points_per_boss: 2500.0,
+        }
+    }
+}
+
+#[derive(Resource)]
+struct GameConfigHandle(Handle<GameConfig>);
+
+#[derive(Default)]
+struct GameConfigLoader;
+
+impl AssetLoader for GameConfigLoader {
+    type Asset = GameConfig;
+    type Settings = ();
+    type Error = Box<dyn std::error::Error + Send + Sync>;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<GameConfig, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut config: GameConfig = ron::de::from_bytes(&bytes)?;
+        config.validate();
+        Ok(config)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["config.ron"]
+    }
+}
+
+pub struct ConfigPlugin;
+
+impl Plugin for ConfigPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameConfig>();
+
+        // Without an asset server (e.g. headless) the defaults are used
+        if app.is_plugin_added::<AssetPlugin>() {
+            app.init_asset::<GameConfig>()
+                .init_asset_loader::<GameConfigLoader>()
+                .add_systems(Startup, load_game_config)
+                .add_systems(Update, apply_game_config);
+        }
+    }
+}
+
+fn load_game_config(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(GameConfigHandle(asset_server.load(GAME_CONFIG_PATH)));
+}
+
+// Copy the loaded asset into the resource on first load and on every reload
+fn apply_game_config(
+    mut events: EventReader<AssetEvent<GameConfig>>,
+    assets: Res<Assets<GameConfig>>,
+    handle: Option<Res<GameConfigHandle>>,
+    mut config: ResMut<GameConfig>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+
+    for event in events.read() {
+        if event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0) {
+            if let Some(loaded) = assets.get(&handle.0) {
+                *config = loaded.clone();
+                info!("Applied game config from {GAME_CONFIG_PATH}");
+            }
+        }
+    }
+}
@@ -0,0 +1,924 @@
+use bevy::color::palettes::css::TOMATO;
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+use rand::{Rng, RngCore};
+use serde::de::{self, Deserializer, Visitor};
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::arena::Arena;
+use crate::bubble::{handle_bubble_collisions, Bubble, BubbleWeapon};
+use crate::collision::{
+    resolve_overlap, BubbleEnemyCollision, Collider, CollisionLayer, CollisionSet,
+    EnemyEnemyCollision,
+};
+use crate::config::{EnemyConfig, GameConfig};
+use crate::explosion::{spawn_explosion, ExplosionType};
+use crate::game_flow::{GameSet, GameplayObject, SimulationSet};
+use crate::physics::{drag_factor, interpolated_position, PreviousPosition, Slowed, Velocity};
+use crate::rng::GameRng;
+use crate::ship::{handle_ship_enemy_collision, Ship};
+use crate::spatial::SpatialGrid;
+use crate::wave::{run_waves, WaveDirector};
+
+pub mod boss;
+pub mod floater;
+pub mod seeker;
+pub mod shooter;
+pub mod splitter;
+
+#[derive(Component)]
+pub struct Enemy {
+    pub health: f32,
+    pub radius: f32, // Fully grown
+    pub kind: EnemyKind,
+    pub color: Color,
+    pub trap_hits: u32, // Hits taken while weakened
+}
+
+// Name of a registered enemy kind. Configs write it bare, like an enum
+// variant: `kind: Seeker`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct EnemyKind(Cow<'static, str>);
+
+impl EnemyKind {
+    pub const fn new(name: &'static str) -> Self {
+        Self(Cow::Borrowed(name))
+    }
+}
+
+impl fmt::Display for EnemyKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for EnemyKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KindVisitor;
+
+        impl Visitor<'_> for KindVisitor {
+            type Value = EnemyKind;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("an enemy kind name")
+            }
+
+            fn visit_str<E: de::Error>(self, name: &str) -> Result<EnemyKind, E> {
+                Ok(EnemyKind(Cow::Owned(name.to_owned())))
+            }
+        }
+
+        deserializer.deserialize_identifier(KindVisitor)
+    }
+}
+
+// Starting values for a fresh enemy
+pub struct EnemyStats {
+    pub health: f32,
+    pub radius: f32,
+    pub mass: f32,
+}
+
+// Everything that sets one kind of enemy apart. Register an implementation
+// with `app.register_enemy(...)` and refer to it by kind in the wave config;
+// the core enemy systems look it up from there. Kinds that need more than
+// this can add their own components in `on_spawn` and systems in a plugin.
+pub trait EnemyBehavior: Send + Sync + 'static {
+    fn kind(&self) -> EnemyKind;
+
+    // Read from the kind's entry in `GameConfig::enemies` unless overridden
+    fn stats(&self, config: &GameConfig) -> EnemyStats {
+        let kind_config = config.enemy_kind(&self.kind());
+        EnemyStats {
+            health: kind_config.health,
+            radius: kind_config.radius,
+            mass: kind_config.mass,
+        }
+    }
+
+    // Relative chance of being picked by wave groups that don't name a kind.
+    // Kinds with 0 are only spawned by name.
+    fn spawn_weight(&self, config: &GameConfig) -> f32 {
+        config.enemy_kind(&self.kind()).spawn_weight
+    }
+
+    // Color of the spawn warning
+    fn color(&self) -> Color;
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        speed_multiplier: f32,
+    ) -> Vec2;
+
+    // Add extra components, e.g. a `Gun`
+    fn on_spawn(&self, _enemy: &mut EntityCommands, _config: &GameConfig) {}
+
+    // Change course, once per tick
+    fn steer(&self, _steering: &Steering, _velocity: &mut Vec2) {}
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing);
+
+    // Runs when a bubble destroys the enemy
+    fn on_death(&self, death: &mut EnemyDeath) {
+        let color = death.enemy.color.into();
+        death.explode(ExplosionType::Enemy { color });
+    }
+
+    fn points(&self, config: &GameConfig) -> f32 {
+        config.score.points_per_enemy
+    }
+
+    // Chance of leaving a pickup behind when destroyed
+    fn drop_chance(&self, config: &GameConfig) -> f32 {
+        config.pickups.drop_chance
+    }
+
+    // Whether enough hits while weakened trap it in a bubble
+    fn can_be_trapped(&self) -> bool {
+        true
+    }
+}
+
+// What an enemy knows about the world when steering
+pub struct Steering<'a> {
+    pub pos: Vec2,
+    pub ship_pos: Option<Vec2>,
+    pub delta_secs: f32,
+    pub speed_multiplier: f32,
+    pub config: &'a GameConfig,
+}
+
+// An enemy about to be drawn. `entity` gives access to any extra components.
+pub struct EnemyDrawing<'a> {
+    pub entity: EntityRef<'a>,
+    pub enemy: &'a Enemy,
+    pub pos: Vec2,
+    pub velocity: Vec2,
+    pub scale: f32, // Shrunk while growing
+    pub alpha: f32,
+    pub health_factor: f32, // Health left, from 1 down to 0
+    pub elapsed_secs: f32,
+    pub config: &'a GameConfig,
+}
+
+// An enemy that was just destroyed
+pub struct EnemyDeath<'a, 'w, 's> {
+    pub commands: &'a mut Commands<'w, 's>,
+    pub rng: &'a mut GameRng,
+    pub config: &'a GameConfig,
+    pub enemy: &'a Enemy,
+    pub pos: Vec2,
+    pub speed_multiplier: f32,
+}
+
+impl EnemyDeath<'_, '_, '_> {
+    pub fn explode(&mut self, explosion_type: ExplosionType) {
+        spawn_explosion(
+            self.commands,
+            &mut self.rng.effects,
+            self.config,
+            self.pos,
+            explosion_type,
+        );
+    }
+}
+
+// Every enemy kind the game knows about
+#[derive(Resource, Default)]
+pub struct EnemyRegistry {
+    behaviors: BTreeMap<EnemyKind, Box<dyn EnemyBehavior>>,
+}
+
+impl EnemyRegistry {
+    // Replaces any behavior registered for the same kind
+    pub fn register(&mut self, behavior: impl EnemyBehavior) {
+        self.behaviors.insert(behavior.kind(), Box::new(behavior));
+    }
+
+    pub fn get(&self, kind: &EnemyKind) -> Option<&dyn EnemyBehavior> {
+        self.behaviors.get(kind).map(|behavior| behavior.as_ref())
+    }
+
+    // Pick a kind by spawn weight, or `None` if nothing can be picked
+    pub fn random_kind(&self, rng: &mut impl Rng, config: &GameConfig) -> Option<EnemyKind> {
+        let weighted = || {
+            self.behaviors
+                .iter()
+                .map(|(kind, behavior)| (kind, behavior.spawn_weight(config)))
+                .filter(|(_, weight)| *weight > 0.0)
+        };
+
+        let total: f32 = weighted().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut pick = rng.gen_range(0.0..total);
+        let mut last = None;
+        for (kind, weight) in weighted() {
+            if pick < weight {
+                return Some(kind.clone());
+            }
+            pick -= weight;
+            last = Some(kind);
+        }
+
+        // Rounding can leave a sliver past the last kind
+        last.cloned()
+    }
+}
+
+pub trait RegisterEnemy {
+    fn register_enemy(&mut self, behavior: impl EnemyBehavior) -> &mut Self;
+}
+
+impl RegisterEnemy for App {
+    fn register_enemy(&mut self, behavior: impl EnemyBehavior) -> &mut Self {
+        self.init_resource::<EnemyRegistry>();
+        self.world_mut()
+            .resource_mut::<EnemyRegistry>()
+            .register(behavior);
+        self
+    }
+}
+
+// Add growth component
+#[derive(Component)]
+pub struct Growing {
+    pub timer: Timer,
+}
+
+impl Growing {
+    // Size relative to a fully grown enemy
+    pub fn scale(&self, config: &EnemyConfig) -> f32 {
+        config.min_scale + (1.0 - config.min_scale) * self.timer.fraction()
+    }
+}
+
+// Shields an enemy from bubbles everywhere but a gap at its back
+#[derive(Component)]
+pub struct Armor {
+    pub facing: Vec2,
+    pub gap: f32, // Radians
+}
+
+impl Armor {
+    // Whether a hit from this direction gets through the gap. The normal
+    // points from the enemy towards whatever hit it.
+    pub fn exposes(&self, normal: Vec2) -> bool {
+        normal.angle_to(-self.facing).abs() <= self.gap * 0.5
+    }
+}
+
+// Caught in a bubble after enough hits while weakened. A trapped enemy drifts
+// harmlessly and bursts when popped or shoved into another enemy, or breaks
+// free when the timer runs out.
+#[derive(Component)]
+pub struct Trapped {
+    pub timer: Timer,
+    pub speed: f32, // Speed to resume when freed
+}
+
+// Marks where an enemy is about to appear
+#[derive(Component)]
+pub struct SpawnWarning {
+    pub kind: EnemyKind,
+    pub timer: Timer,
+}
+
+// Add enemy destroyed event
+#[derive(Event)]
+pub struct EnemyDestroyed {
+    pub kind: EnemyKind,
+    pub pos: Vec2,
+    pub popped: bool, // Burst while trapped
+}
+
+// Add enemy hit event
+#[derive(Event)]
+pub struct EnemyHit;
+
+pub struct EnemyPlugin;
+
+impl Plugin for EnemyPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_enemy(floater::FloaterBehavior)
+            .register_enemy(seeker::SeekerBehavior)
+            .register_enemy(splitter::SplitterBehavior)
+            .register_enemy(splitter::ShardBehavior)
+            .register_enemy(shooter::ShooterBehavior)
+            .add_event::<EnemyDestroyed>()
+            .add_event::<EnemyHit>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    (
+                        handle_bubble_hits,
+                        handle_trapped_impacts,
+                        separate_enemies,
+                        destroy_dead_enemies,
+                    )
+                        .chain()
+                        .in_set(CollisionSet)
+                        .after(handle_bubble_collisions)
+                        .after(handle_ship_enemy_collision),
+                    (
+                        sync_enemy_colliders.run_if(resource_changed::<GameConfig>),
+                        update_enemy_growth,
+                    )
+                        .chain()
+                        .in_set(SimulationSet::Upkeep)
+                        .after(run_waves),
+                    (
+                        update_spawn_warnings,
+                        update_slowed,
+                        update_trapped,
+                        steer_enemies,
+                    )
+                        .chain()
+                        .in_set(SimulationSet::Act),
+                    handle_enemy_border.in_set(SimulationSet::Resolve),
+                )
+                    .in_set(GameSet::Simulation),
+            )
+            .add_systems(
+                Update,
+                (draw_spawn_warnings, draw_enemies).in_set(GameSet::Draw),
+            );
+    }
+}
+
+// Add helper function to calculate current speed multiplier
+pub fn get_enemy_speed_multiplier(elapsed_time: f32, config: &EnemyConfig) -> f32 {
+    1.0 + (elapsed_time / config.speed_scale_time * (config.max_speed_multiplier - 1.0))
+        .min(config.max_speed_multiplier - 1.0)
+}
+
+// Telegraph an enemy spawn. The enemy appears once the warning runs out.
+pub fn spawn_enemy_warning(
+    commands: &mut Commands,
+    config: &GameConfig,
+    kind: EnemyKind,
+    pos: Vec2,
+) {
+    commands.spawn((
+        SpawnWarning {
+            kind,
+            timer: Timer::from_seconds(config.enemy.spawn_warning_time, TimerMode::Once),
+        },
+        Transform::from_xyz(pos.x, pos.y, 0.0),
+        GameplayObject,
+    ));
+}
+
+// Spawn a single enemy. Enemies start small and cannot hurt the ship while
+// `Growing`.
+pub fn spawn_enemy(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    config: &GameConfig,
+    behavior: &dyn EnemyBehavior,
+    pos: Vec2,
+    speed_multiplier: f32,
+) {
+    let enemy_config = &config.enemy;
+    let stats = behavior.stats(config);
+
+    let hue = rng.gen_range(0.0..360.0);
+    let enemy_color = Color::hsl(hue, 0.8, 0.7);
+
+    let velocity = behavior.initial_velocity(rng, config, speed_multiplier);
+
+    let mut enemy = commands.spawn((
+        Enemy {
+            health: stats.health,
+            radius: stats.radius,
+            kind: behavior.kind(),
+            color: enemy_color,
+            trap_hits: 0,
+        },
+        Transform::from_xyz(pos.x, pos.y, 0.0),
+        Velocity(velocity),
+        Collider {
+            radius: stats.radius * enemy_config.min_scale,
+            mass: stats.mass,
+            layer: CollisionLayer::Enemy,
+        },
+        Growing {
+            timer: Timer::from_seconds(enemy_config.growth_time, TimerMode::Once),
+        },
+        GameplayObject,
+    ));
+
+    behavior.on_spawn(&mut enemy, config);
+}
+
+pub(crate) fn random_direction(rng: &mut (impl Rng + ?Sized)) -> Vec2 {
+    Vec2::from_angle(rng.gen_range(0.0..std::f32::consts::TAU))
+}
+
+pub(crate) fn update_spawn_warnings(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Transform, &mut SpawnWarning)>,
+    mut game_rng: ResMut<GameRng>,
+    time: Res<Time>,
+    waves: Res<WaveDirector>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    let speed_multiplier = get_enemy_speed_multiplier(waves.elapsed_time, &config.enemy);
+
+    for (entity, transform, mut warning) in &mut query {
+        warning.timer.tick(time.delta());
+        if warning.timer.finished() {
+            commands.entity(entity).despawn();
+
+            let Some(behavior) = registry.get(&warning.kind) else {
+                warn!("No enemy kind named {} is registered", warning.kind);
+                continue;
+            };
+            spawn_enemy(
+                &mut commands,
+                &mut game_rng.enemies,
+                &config,
+                behavior,
+                transform.translation.truncate(),
+                speed_multiplier,
+            );
+        }
+    }
+}
+
+// Pick up radius and mass changes from a reloaded config
+fn sync_enemy_colliders(
+    mut query: Query<(&mut Enemy, &mut Collider, Option<&Growing>)>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    for (mut enemy, mut collider, growing) in &mut query {
+        let Some(behavior) = registry.get(&enemy.kind) else {
+            continue;
+        };
+
+        let stats = behavior.stats(&config);
+        let scale = growing.map_or(1.0, |growing| growing.scale(&config.enemy));
+        enemy.radius = stats.radius;
+        collider.radius = stats.radius * scale;
+        collider.mass = stats.mass;
+    }
+}
+
+fn update_enemy_growth(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Enemy, &mut Growing, &mut Collider)>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    for (entity, enemy, mut growing, mut collider) in &mut query {
+        growing.timer.tick(time.delta());
+        collider.radius = enemy.radius * growing.scale(&config.enemy);
+        if growing.timer.finished() {
+            commands.entity(entity).remove::<Growing>();
+        }
+    }
+}
+
+fn steer_enemies(
+    mut enemy_query: Query<(&Transform, &mut Velocity, &Enemy), Without<Trapped>>,
+    ship_query: Query<&Transform, With<Ship>>,
+    time: Res<Time>,
+    waves: Res<WaveDirector>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    let ship_pos = ship_query
+        .get_single()
+        .map(|transform| transform.translation.truncate())
+        .ok();
+    let speed_multiplier = get_enemy_speed_multiplier(waves.elapsed_time, &config.enemy);
+
+    for (transform, mut velocity, enemy) in &mut enemy_query {
+        let Some(behavior) = registry.get(&enemy.kind) else {
+            continue;
+        };
+
+        let steering = Steering {
+            pos: transform.translation.truncate(),
+            ship_pos,
+            delta_secs: time.delta_secs(),
+            speed_multiplier,
+            config: &config,
+        };
+        behavior.steer(&steering, &mut velocity.0);
+    }
+}
+
+fn draw_spawn_warnings(
+    mut gizmos: Gizmos,
+    query: Query<(&Transform, &SpawnWarning)>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    for (transform, warning) in &query {
+        let Some(behavior) = registry.get(&warning.kind) else {
+            continue;
+        };
+
+        let pos = transform.translation.truncate();
+        let radius = behavior.stats(&config).radius;
+        let progress = warning.timer.fraction();
+
+        // Blink faster as the spawn gets closer
+        let blink = (progress * progress * 40.0).sin() * 0.5 + 0.5;
+        let color = behavior.color().with_alpha(0.3 + 0.5 * blink);
+
+        // Ring closing in on the spawn point
+        gizmos.circle_2d(pos, radius * (2.0 - progress), color);
+
+        // Cross at the spawn point
+        let arm = radius * 0.5;
+        gizmos.line_2d(pos - Vec2::splat(arm), pos + Vec2::splat(arm), color);
+        gizmos.line_2d(
+            pos + Vec2::new(-arm, arm),
+            pos + Vec2::new(arm, -arm),
+            color,
+        );
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_enemies(
+    mut gizmos: Gizmos,
+    query: Query<(
+        EntityRef,
+        &Transform,
+        &PreviousPosition,
+        &Velocity,
+        &Enemy,
+        Option<&Growing>,
+    )>,
+    fixed_time: Res<Time<Fixed>>,
+    time: Res<Time>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    for (entity, transform, previous, velocity, enemy, growing) in &query {
+        let Some(behavior) = registry.get(&enemy.kind) else {
+            continue;
+        };
+
+        let (scale, alpha) = if let Some(growing) = growing {
+            (growing.scale(&config.enemy), growing.timer.fraction())
+        } else {
+            (1.0, 1.0)
+        };
+        let pos = interpolated_position(transform, previous, &fixed_time);
+
+        behavior.draw(
+            &mut gizmos,
+            &EnemyDrawing {
+                entity,
+                enemy,
+                pos,
+                velocity: velocity.0,
+                scale,
+                alpha,
+                health_factor: enemy.health / behavior.stats(&config).health,
+                elapsed_secs: time.elapsed_secs(),
+                config: &config,
+            },
+        );
+
+        // Goo clinging to slowed enemies
+        if entity.contains::<Slowed>() {
+            gizmos.circle_2d(
+                pos,
+                (enemy.radius + 4.0) * scale,
+                Color::srgba(0.6, 1.0, 0.3, 0.5 * alpha),
+            );
+        }
+
+        let trap_color = Color::srgba(0.7, 0.9, 1.0, 0.7 * alpha);
+        if let Some(trapped) = entity.get::<Trapped>() {
+            // Bubble around a trapped enemy, blinking before it breaks free
+            let remaining = trapped.timer.remaining_secs();
+            if remaining > 1.0 || ((remaining * 10.0) as u32).is_multiple_of(2) {
+                let radius = enemy.radius * 1.5 * scale;
+                gizmos.circle_2d(pos, radius, trap_color);
+                gizmos.circle_2d(
+                    pos + Vec2::new(-radius * 0.35, radius * 0.35),
+                    radius * 0.2,
+                    Color::WHITE.with_alpha(0.5 * alpha),
+                );
+            }
+        } else if enemy.trap_hits > 0 {
+            // How close a weakened enemy is to being trapped
+            let progress = enemy.trap_hits as f32 / config.trap.hits_to_trap.max(1) as f32;
+            gizmos.arc_2d(
+                Isometry2d::from_translation(pos),
+                std::f32::consts::TAU * progress,
+                (enemy.radius + 6.0) * scale,
+                trap_color,
+            );
+        }
+    }
+}
+
+// Trapped enemies coast down to a drift, and resume their old speed when
+// the bubble runs out
+fn update_trapped(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Enemy, &mut Velocity, &mut Trapped)>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    for (entity, mut enemy, mut velocity, mut trapped) in &mut query {
+        if trapped.timer.tick(time.delta()).finished() {
+            velocity.0 = velocity.0.normalize_or(Vec2::Y) * trapped.speed;
+            enemy.trap_hits = 0;
+            commands.entity(entity).remove::<Trapped>();
+        } else if velocity.0.length() > config.trap.drift_speed {
+            velocity.0 *= drag_factor(config.trap.drag, time.delta_secs());
+        }
+    }
+}
+
+// Another sticky hit while slowed only restarts the timer
+fn update_slowed(mut commands: Commands, mut query: Query<(Entity, &mut Slowed)>, time: Res<Time>) {
+    for (entity, mut slowed) in &mut query {
+        if slowed.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Slowed>();
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn handle_bubble_hits(
+    mut commands: Commands,
+    mut collisions: EventReader<BubbleEnemyCollision>,
+    bubble_query: Query<(&Transform, &Bubble, &Velocity, &Collider)>,
+    mut enemy_query: Query<
+        (
+            &Transform,
+            &mut Enemy,
+            &mut Velocity,
+            &Collider,
+            Option<&Armor>,
+            Has<Trapped>,
+        ),
+        Without<Bubble>,
+    >,
+    mut enemy_hit: EventWriter<EnemyHit>,
+    mut game_rng: ResMut<GameRng>,
+    grid: Res<SpatialGrid>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    let trap = &config.trap;
+    let mut destroyed_bubbles = EntityHashSet::default();
+    let mut newly_trapped = EntityHashSet::default();
+    let mut blasts: Vec<Vec2> = Vec::new();
+
+    for collision in collisions.read() {
+        // Bubble can only hit one enemy
+        if destroyed_bubbles.contains(&collision.bubble) {
+            continue;
+        }
+        let Ok((bubble_transform, bubble, bubble_velocity, bubble_collider)) =
+            bubble_query.get(collision.bubble)
+        else {
+            continue;
+        };
+        let Ok((_, mut enemy, mut enemy_velocity, enemy_collider, armor, trapped)) =
+            enemy_query.get_mut(collision.enemy)
+        else {
+            continue;
+        };
+
+        // Skip enemies already destroyed this tick
+        if enemy.health <= 0.0 {
+            continue;
+        }
+        destroyed_bubbles.insert(collision.bubble);
+
+        // Mines hurt everything around them instead, handled below
+        if bubble.weapon == BubbleWeapon::Mine {
+            blasts.push(bubble_transform.translation.truncate());
+            continue;
+        }
+
+        // A trapped enemy bursts at the first touch
+        if trapped || newly_trapped.contains(&collision.enemy) {
+            enemy.health = 0.0;
+            enemy_hit.send(EnemyHit);
+            continue;
+        }
+
+        // The bubble pops and hands its momentum to the enemy
+        enemy_velocity.0 += bubble_velocity.0 * bubble_collider.mass / enemy_collider.mass;
+
+        // Armor shrugs off anything that misses the gap
+        if armor.is_some_and(|armor| !armor.exposes(collision.normal)) {
+            continue;
+        }
+
+        enemy.health -= bubble.damage(&config);
+        enemy_hit.send(EnemyHit);
+
+        if bubble.weapon == BubbleWeapon::Sticky {
+            let sticky = &config.weapons.sticky;
+            commands.entity(collision.enemy).insert(Slowed {
+                factor: sticky.slow_factor.clamp(0.05, 1.0),
+                timer: Timer::from_seconds(sticky.slow_time, TimerMode::Once),
+            });
+        }
+
+        // Enough hits on a weakened enemy trap it in a bubble
+        let Some(behavior) = registry.get(&enemy.kind) else {
+            continue;
+        };
+        if enemy.health <= 0.0
+            || !behavior.can_be_trapped()
+            || enemy.health > behavior.stats(&config).health * trap.weak_health
+        {
+            continue;
+        }
+        enemy.trap_hits += 1;
+        if enemy.trap_hits >= trap.hits_to_trap {
+            commands.entity(collision.enemy).insert(Trapped {
+                timer: Timer::from_seconds(trap.duration, TimerMode::Once),
+                speed: enemy_velocity.0.length(),
+            });
+            enemy_velocity.0 = enemy_velocity.0.normalize_or_zero() * trap.drift_speed;
+            newly_trapped.insert(collision.enemy);
+        }
+    }
+
+    // Blasts push every enemy in range away, and damage those whose armor
+    // gap faces the blast
+    let mine = &config.weapons.mine;
+    for blast_pos in blasts {
+        spawn_explosion(
+            &mut commands,
+            &mut game_rng.effects,
+            &config,
+            blast_pos,
+            ExplosionType::Enemy { color: TOMATO },
+        );
+
+        for (entity, _) in grid.query(blast_pos, mine.blast_radius + grid.max_radius()) {
+            let Ok((transform, mut enemy, mut velocity, collider, armor, trapped)) =
+                enemy_query.get_mut(entity)
+            else {
+                continue;
+            };
+            if enemy.health <= 0.0 {
+                continue;
+            }
+            let offset = blast_pos - transform.translation.truncate();
+            if offset.length() > mine.blast_radius + collider.radius {
+                continue;
+            }
+
+            let normal = offset.normalize_or(Vec2::Y);
+            velocity.0 -= normal * mine.blast_force / collider.mass;
+            if armor.is_some_and(|armor| !armor.exposes(normal)) {
+                continue;
+            }
+
+            enemy.health = if trapped {
+                0.0
+            } else {
+                enemy.health - mine.damage
+            };
+            enemy_hit.send(EnemyHit);
+        }
+    }
+
+    // Despawn all at once after collision checks
+    for entity in destroyed_bubbles {
+        commands.entity(entity).despawn();
+    }
+}
+
+// A trapped enemy shoved into another one fast enough bursts and hurts it
+fn handle_trapped_impacts(
+    mut collisions: EventReader<EnemyEnemyCollision>,
+    mut enemy_query: Query<(&mut Enemy, &Velocity, Option<&Armor>, Has<Trapped>)>,
+    mut enemy_hit: EventWriter<EnemyHit>,
+    config: Res<GameConfig>,
+) {
+    for collision in collisions.read() {
+        let Ok([a, b]) = enemy_query.get_many_mut([collision.a, collision.b]) else {
+            continue;
+        };
+
+        // The normal from the target towards the trapped enemy
+        let (mut trapped, mut target, normal) = match (a.3, b.3) {
+            (true, false) => (a, b, collision.normal),
+            (false, true) => (b, a, -collision.normal),
+            _ => continue,
+        };
+        if trapped.0.health <= 0.0 || target.0.health <= 0.0 {
+            continue;
+        }
+        if (trapped.1 .0 - target.1 .0).length() < config.trap.impact_speed {
+            continue;
+        }
+
+        trapped.0.health = 0.0;
+        if target.2.is_none_or(|armor| armor.exposes(normal)) {
+            target.0.health -= config.trap.impact_damage;
+        }
+        enemy_hit.send(EnemyHit);
+    }
+}
+
+// Let each kind go out its own way once its health runs out
+pub(crate) fn destroy_dead_enemies(
+    mut commands: Commands,
+    enemy_query: Query<(Entity, &Transform, &Enemy, Has<Trapped>)>,
+    mut enemy_destroyed: EventWriter<EnemyDestroyed>,
+    mut game_rng: ResMut<GameRng>,
+    waves: Res<WaveDirector>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    let speed_multiplier = get_enemy_speed_multiplier(waves.elapsed_time, &config.enemy);
+
+    for (entity, transform, enemy, trapped) in &enemy_query {
+        if enemy.health > 0.0 {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+
+        if let Some(behavior) = registry.get(&enemy.kind) {
+            behavior.on_death(&mut EnemyDeath {
+                commands: &mut commands,
+                rng: &mut game_rng,
+                config: &config,
+                enemy,
+                pos,
+                speed_multiplier,
+            });
+        }
+
+        enemy_destroyed.send(EnemyDestroyed {
+            kind: enemy.kind.clone(),
+            pos,
+            popped: trapped,
+        });
+        commands.entity(entity).despawn();
+    }
+}
+
+// Push overlapping enemies apart and bounce them off each other, so they
+// don't pile up
+fn separate_enemies(
+    mut collisions: EventReader<EnemyEnemyCollision>,
+    mut bodies: Query<(&mut Transform, &mut Velocity, &Collider), With<Enemy>>,
+    config: Res<GameConfig>,
+) {
+    for collision in collisions.read() {
+        let Ok(
+            [(mut transform_a, mut velocity_a, collider_a), (mut transform_b, mut velocity_b, collider_b)],
+        ) = bodies.get_many_mut([collision.a, collision.b])
+        else {
+            continue;
+        };
+
+        resolve_overlap(
+            (&mut transform_a, &mut velocity_a, collider_a),
+            (&mut transform_b, &mut velocity_b, collider_b),
+            collision.normal,
+            config.enemy.restitution,
+        );
+    }
+}
+
+fn handle_enemy_border(
+    mut enemy_query: Query<(&mut Transform, &mut Velocity, &Enemy, Has<Trapped>)>,
+    arena: Res<Arena>,
+    waves: Res<WaveDirector>,
+    config: Res<GameConfig>,
+) {
+    let speed_multiplier = get_enemy_speed_multiplier(waves.elapsed_time, &config.enemy);
+
+    for (mut transform, mut velocity, enemy, trapped) in &mut enemy_query {
+        let bounced =
+            arena.apply_topology(&config.border, &mut transform.translation, &mut velocity.0);
+
+        // Increase speed gradually with every bounce
+        let max_speed = config.enemy_kind(&enemy.kind).speed * speed_multiplier;
+        let speed = velocity.0.length();
+        if bounced && !trapped && speed < max_speed {
+            let new_speed = (speed + config.enemy.speed_increase_per_bounce).min(max_speed);
+            velocity.0 = velocity.0.normalize_or_zero() * new_speed;
+        }
+    }
+}
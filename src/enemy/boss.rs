@@ -0,0 +1,356 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use rand::RngCore;
+use std::f32::consts::{FRAC_PI_2, TAU};
+
+use crate::collision::Collider;
+use crate::config::{BossConfig, GameConfig};
+use crate::enemy::{
+    random_direction, steer_enemies, Armor, Enemy, EnemyBehavior, EnemyDeath, EnemyDrawing,
+    EnemyKind, EnemyStats, Growing, RegisterEnemy,
+};
+use crate::explosion::ExplosionType;
+use crate::game_flow::{GameSet, GameState, SimulationSet};
+use crate::physics::{drag_factor, Velocity};
+use crate::projectile::spawn_projectile;
+use crate::ship::Ship;
+
+pub const BOSS: EnemyKind = EnemyKind::new("Boss");
+
+// Large enemy that closes boss waves. Its `Armor` turns to face the ship so
+// only the back takes damage.
+#[derive(Component)]
+pub struct Boss {
+    pub phase: BossPhase,
+    pub attack_timer: Timer,
+}
+
+// Attack pattern, picked by how much health the boss has left
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BossPhase {
+    Volley, // Aimed spreads of projectiles
+    Burst,  // Rings of projectiles in every direction
+    Charge, // Rushes at the ship
+}
+
+impl BossPhase {
+    fn from_health(fraction: f32, config: &BossConfig) -> Self {
+        if fraction > config.burst_health {
+            BossPhase::Volley
+        } else if fraction > config.charge_health {
+            BossPhase::Burst
+        } else {
+            BossPhase::Charge
+        }
+    }
+
+    fn attack_timer(self, config: &BossConfig) -> Timer {
+        let interval = match self {
+            BossPhase::Volley => config.volley_interval,
+            BossPhase::Burst => config.burst_interval,
+            BossPhase::Charge => config.charge_interval,
+        };
+        Timer::from_seconds(interval, TimerMode::Repeating)
+    }
+
+    fn color(self) -> Srgba {
+        match self {
+            BossPhase::Volley => GOLD,
+            BossPhase::Burst => ORANGE_RED,
+            BossPhase::Charge => CRIMSON,
+        }
+    }
+}
+
+impl Boss {
+    pub fn new(config: &BossConfig) -> Self {
+        Self {
+            phase: BossPhase::Volley,
+            attack_timer: BossPhase::Volley.attack_timer(config),
+        }
+    }
+}
+
+pub struct BossBehavior;
+
+impl EnemyBehavior for BossBehavior {
+    fn kind(&self) -> EnemyKind {
+        BOSS
+    }
+
+    fn stats(&self, config: &GameConfig) -> EnemyStats {
+        EnemyStats {
+            health: config.boss.health,
+            radius: config.boss.radius,
+            mass: config.boss.mass,
+        }
+    }
+
+    fn color(&self) -> Color {
+        CRIMSON.into()
+    }
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        _speed_multiplier: f32,
+    ) -> Vec2 {
+        random_direction(rng) * config.boss.speed
+    }
+
+    fn on_spawn(&self, enemy: &mut EntityCommands, config: &GameConfig) {
+        enemy.insert((
+            Boss::new(&config.boss),
+            Armor {
+                facing: Vec2::Y,
+                gap: config.boss.weak_point_arc,
+            },
+        ));
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing) {
+        let (Some(boss), Some(armor)) =
+            (drawing.entity.get::<Boss>(), drawing.entity.get::<Armor>())
+        else {
+            return;
+        };
+        let EnemyDrawing {
+            pos, scale, alpha, ..
+        } = *drawing;
+        let radius = drawing.enemy.radius * scale;
+        let phase_color = boss.phase.color();
+
+        // Body - darken with damage
+        let body_color: Color = Hsla::from(drawing.enemy.color)
+            .with_lightness(0.2 + 0.5 * drawing.health_factor)
+            .into();
+        gizmos.circle_2d(pos, radius, body_color.with_alpha(alpha));
+
+        // Armor covering everything but the back
+        let armor_arc = TAU - armor.gap;
+        let start = armor.facing.to_angle() - armor_arc * 0.5 - FRAC_PI_2;
+        for layer in 0..3 {
+            gizmos
+                .arc_2d(
+                    Isometry2d::new(pos, Rot2::radians(start)),
+                    armor_arc,
+                    radius * (1.05 + layer as f32 * 0.08),
+                    phase_color.with_alpha(alpha),
+                )
+                .resolution(48);
+        }
+
+        // Eye looking at the ship
+        gizmos.circle_2d(
+            pos + armor.facing * radius * 0.55,
+            radius * 0.15,
+            phase_color.with_alpha(alpha),
+        );
+
+        // Pulsing weak point on the back
+        let pulse = (drawing.elapsed_secs * 6.0).sin() * 0.5 + 0.5;
+        gizmos.circle_2d(
+            pos - armor.facing * radius * 0.6,
+            radius * (0.15 + 0.05 * pulse),
+            Color::WHITE.with_alpha(alpha * (0.5 + 0.5 * pulse)),
+        );
+
+        // Telegraph the charge direction
+        if boss.phase == BossPhase::Charge && !drawing.entity.contains::<Growing>() {
+            let windup = boss.attack_timer.fraction();
+            gizmos.line_2d(
+                pos + armor.facing * radius,
+                pos + armor.facing * radius * (1.0 + 3.0 * windup),
+                RED.with_alpha(windup),
+            );
+        }
+    }
+
+    fn on_death(&self, death: &mut EnemyDeath) {
+        death.explode(ExplosionType::Boss);
+    }
+
+    fn points(&self, config: &GameConfig) -> f32 {
+        config.score.points_per_boss
+    }
+
+    fn drop_chance(&self, _config: &GameConfig) -> f32 {
+        1.0
+    }
+
+    fn can_be_trapped(&self) -> bool {
+        false
+    }
+}
+
+// Add boss health bar
+#[derive(Component)]
+struct BossHealthBar;
+
+#[derive(Component)]
+struct BossHealthFill;
+
+pub struct BossPlugin;
+
+impl Plugin for BossPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_enemy(BossBehavior)
+            .add_systems(Startup, spawn_boss_health_bar)
+            .add_systems(OnEnter(GameState::GameOver), hide_boss_health_bar)
+            .add_systems(
+                FixedUpdate,
+                update_bosses
+                    .in_set(SimulationSet::Act)
+                    .after(steer_enemies),
+            )
+            .add_systems(Update, update_boss_health_bar.in_set(GameSet::Draw));
+    }
+}
+
+// Turn the armor toward the ship, switch phases as health drops and attack.
+// Bosses don't attack while they are still growing.
+#[allow(clippy::type_complexity)]
+pub(crate) fn update_bosses(
+    mut commands: Commands,
+    mut boss_query: Query<(
+        &Transform,
+        &mut Velocity,
+        &Collider,
+        &Enemy,
+        &mut Boss,
+        &mut Armor,
+        Has<Growing>,
+    )>,
+    ship_query: Query<&Transform, With<Ship>>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let Ok(ship_transform) = ship_query.get_single() else {
+        return;
+    };
+    let ship_pos = ship_transform.translation.truncate();
+
+    let boss_config = &config.boss;
+    let max_turn = boss_config.turn_rate * time.delta_secs();
+
+    for (transform, mut velocity, collider, enemy, mut boss, mut armor, growing) in &mut boss_query
+    {
+        let pos = transform.translation.truncate();
+
+        let to_ship = (ship_pos - pos).normalize_or(armor.facing);
+        let turn = armor.facing.angle_to(to_ship).clamp(-max_turn, max_turn);
+        armor.facing = Vec2::from_angle(turn).rotate(armor.facing);
+
+        // Slow back down after a charge
+        if velocity.0.length() > boss_config.speed {
+            velocity.0 *= drag_factor(boss_config.charge_drag, time.delta_secs());
+        }
+
+        let phase = BossPhase::from_health(enemy.health / boss_config.health, boss_config);
+        if phase != boss.phase {
+            boss.phase = phase;
+            boss.attack_timer = phase.attack_timer(boss_config);
+        }
+
+        if growing || !boss.attack_timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        match boss.phase {
+            BossPhase::Volley => {
+                let count = boss_config.volley_count.max(1);
+                for i in 0..count {
+                    let t = if count == 1 {
+                        0.5
+                    } else {
+                        i as f32 / (count - 1) as f32
+                    };
+                    let angle = (t - 0.5) * boss_config.volley_spread;
+                    let direction = Vec2::from_angle(angle).rotate(armor.facing);
+                    spawn_projectile(&mut commands, &config, pos, collider.radius, direction);
+                }
+            }
+            BossPhase::Burst => {
+                let count = boss_config.burst_count.max(1);
+                let offset = armor.facing.to_angle();
+                for i in 0..count {
+                    let direction = Vec2::from_angle(offset + i as f32 * TAU / count as f32);
+                    spawn_projectile(&mut commands, &config, pos, collider.radius, direction);
+                }
+            }
+            BossPhase::Charge => {
+                velocity.0 = armor.facing * boss_config.charge_speed;
+            }
+        }
+    }
+}
+
+fn spawn_boss_health_bar(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Percent(30.0),
+                top: Val::Px(10.0),
+                width: Val::Percent(40.0),
+                height: Val::Px(16.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BorderColor(Color::WHITE),
+            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+            Visibility::Hidden,
+            BossHealthBar,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Node {
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::from(GOLD)),
+                BossHealthFill,
+            ));
+        });
+}
+
+// Shows the combined health of every boss alive
+fn update_boss_health_bar(
+    boss_query: Query<&Enemy, With<Boss>>,
+    mut bar_query: Query<&mut Visibility, With<BossHealthBar>>,
+    mut fill_query: Query<(&mut Node, &mut BackgroundColor), With<BossHealthFill>>,
+    config: Res<GameConfig>,
+) {
+    let Ok(mut visibility) = bar_query.get_single_mut() else {
+        return;
+    };
+
+    let max_health = config.boss.health;
+    let (health, total) = boss_query
+        .iter()
+        .fold((0.0, 0.0), |(health, total), enemy| {
+            (health + enemy.health.max(0.0), total + max_health)
+        });
+
+    if total <= 0.0 {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+    *visibility = Visibility::Inherited;
+
+    if let Ok((mut node, mut background)) = fill_query.get_single_mut() {
+        let fraction = health / total;
+        node.width = Val::Percent(fraction * 100.0);
+        background.0 = BossPhase::from_health(fraction, &config.boss)
+            .color()
+            .into();
+    }
+}
+
+fn hide_boss_health_bar(mut bar_query: Query<&mut Visibility, With<BossHealthBar>>) {
+    for mut visibility in &mut bar_query {
+        *visibility = Visibility::Hidden;
+    }
+}
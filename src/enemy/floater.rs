@@ -0,0 +1,66 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use rand::{Rng, RngCore};
+
+use crate::config::GameConfig;
+use crate::enemy::{EnemyBehavior, EnemyDrawing, EnemyKind};
+
+pub const FLOATER: EnemyKind = EnemyKind::new("Floater");
+
+// Drifts in a straight line and bounces around the arena
+pub struct FloaterBehavior;
+
+impl EnemyBehavior for FloaterBehavior {
+    fn kind(&self) -> EnemyKind {
+        FLOATER
+    }
+
+    fn color(&self) -> Color {
+        RED.into()
+    }
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        speed_multiplier: f32,
+    ) -> Vec2 {
+        let max_speed = config.enemy_kind(&FLOATER).speed * speed_multiplier;
+        Vec2::new(
+            rng.gen_range(-max_speed..=max_speed),
+            rng.gen_range(-max_speed..=max_speed),
+        )
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing) {
+        let EnemyDrawing {
+            pos, scale, alpha, ..
+        } = *drawing;
+        let radius = drawing.enemy.radius;
+
+        let base_color = drawing.enemy.color;
+        let dark_color: Color = Hsla::from(base_color).with_lightness(0.3).into();
+
+        // Main body - darken with damage
+        let body_color: Color = Hsla::from(base_color)
+            .with_lightness(0.7 * drawing.health_factor)
+            .into();
+        gizmos.circle_2d(pos, radius * scale, body_color.with_alpha(alpha));
+
+        // Inner ring
+        gizmos.circle_2d(pos, radius * 0.7 * scale, dark_color.with_alpha(alpha));
+
+        // Core
+        gizmos.circle_2d(pos, radius * 0.3 * scale, Color::WHITE.with_alpha(alpha));
+
+        // Spikes
+        let spikes = 8;
+        for i in 0..spikes {
+            let angle = i as f32 * std::f32::consts::TAU / spikes as f32;
+            let dir = Vec2::new(angle.cos(), angle.sin());
+            let inner = pos + dir * radius * 0.8 * scale;
+            let outer = pos + dir * radius * 1.2 * scale;
+            gizmos.line_2d(inner, outer, dark_color.with_alpha(alpha * 0.8));
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use rand::RngCore;
+
+use crate::config::GameConfig;
+use crate::enemy::{random_direction, EnemyBehavior, EnemyDrawing, EnemyKind, Steering};
+
+pub const SEEKER: EnemyKind = EnemyKind::new("Seeker");
+
+// Turns toward the ship, limited by its turn rate
+pub struct SeekerBehavior;
+
+impl EnemyBehavior for SeekerBehavior {
+    fn kind(&self) -> EnemyKind {
+        SEEKER
+    }
+
+    fn color(&self) -> Color {
+        ORANGE.into()
+    }
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        speed_multiplier: f32,
+    ) -> Vec2 {
+        random_direction(rng) * config.enemy_kind(&SEEKER).speed * speed_multiplier
+    }
+
+    fn steer(&self, steering: &Steering, velocity: &mut Vec2) {
+        let Some(ship_pos) = steering.ship_pos else {
+            return;
+        };
+
+        let to_ship = (ship_pos - steering.pos).normalize_or_zero();
+        if to_ship == Vec2::ZERO {
+            return;
+        }
+
+        let seeker_config = steering.config.enemy_kind(&SEEKER);
+        let max_turn = seeker_config.turn_rate * steering.delta_secs;
+        let heading = velocity.normalize_or(to_ship);
+        let turn = heading.angle_to(to_ship).clamp(-max_turn, max_turn);
+        *velocity = Vec2::from_angle(turn).rotate(heading)
+            * seeker_config.speed
+            * steering.speed_multiplier;
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing) {
+        let EnemyDrawing {
+            pos, scale, alpha, ..
+        } = *drawing;
+        let radius = drawing.enemy.radius;
+
+        // Triangular body, pointing along the heading
+        let forward = drawing.velocity.normalize_or(Vec2::Y) * scale;
+        let points = [
+            pos + forward * radius * 1.2,
+            pos + forward.rotate(Vec2::from_angle(2.3)) * radius,
+            pos + forward.rotate(Vec2::from_angle(-2.3)) * radius,
+        ];
+
+        // Fill
+        gizmos.circle_2d(pos, radius * 0.7 * scale, ORANGE.with_alpha(alpha * 0.5));
+
+        // Outline
+        for i in 0..points.len() {
+            let start = points[i];
+            let end = points[(i + 1) % points.len()];
+            gizmos.line_2d(start, end, ORANGE.with_alpha(alpha));
+        }
+
+        // Core
+        gizmos.circle_2d(pos, radius * 0.3 * scale, YELLOW.with_alpha(alpha));
+    }
+}
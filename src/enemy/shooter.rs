@@ -0,0 +1,68 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use rand::RngCore;
+
+use crate::config::GameConfig;
+use crate::enemy::{random_direction, EnemyBehavior, EnemyDrawing, EnemyKind};
+use crate::projectile::Gun;
+
+pub const SHOOTER: EnemyKind = EnemyKind::new("Shooter");
+
+// Drifts in a straight line like a Floater and fires projectiles at the ship
+pub struct ShooterBehavior;
+
+impl EnemyBehavior for ShooterBehavior {
+    fn kind(&self) -> EnemyKind {
+        SHOOTER
+    }
+
+    fn color(&self) -> Color {
+        FUCHSIA.into()
+    }
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        speed_multiplier: f32,
+    ) -> Vec2 {
+        random_direction(rng) * config.enemy_kind(&SHOOTER).speed * speed_multiplier
+    }
+
+    fn on_spawn(&self, enemy: &mut EntityCommands, config: &GameConfig) {
+        enemy.insert(Gun::new(config));
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing) {
+        let EnemyDrawing {
+            pos, scale, alpha, ..
+        } = *drawing;
+        let radius = drawing.enemy.radius;
+
+        // Square body - darken with damage
+        let body_color: Color = Hsla::from(drawing.enemy.color)
+            .with_lightness(0.7 * drawing.health_factor)
+            .into();
+        gizmos.rect_2d(
+            pos,
+            Vec2::splat(radius * 1.6 * scale),
+            body_color.with_alpha(alpha),
+        );
+
+        if let Some(gun) = drawing.entity.get::<Gun>() {
+            // Barrel along the last shot
+            gizmos.line_2d(
+                pos,
+                pos + gun.aim * radius * 1.3 * scale,
+                FUCHSIA.with_alpha(alpha),
+            );
+
+            // Charge for the next shot
+            gizmos.circle_2d(
+                pos,
+                radius * 0.5 * scale * gun.timer.fraction(),
+                FUCHSIA.with_alpha(alpha),
+            );
+        }
+    }
+}
@@ -0,0 +1,160 @@
+use bevy::color::palettes::css::*;
+use bevy::prelude::*;
+use rand::{Rng, RngCore};
+
+use crate::collision::{Collider, CollisionLayer};
+use crate::config::GameConfig;
+use crate::enemy::{random_direction, Enemy, EnemyBehavior, EnemyDeath, EnemyDrawing, EnemyKind};
+use crate::explosion::ExplosionType;
+use crate::game_flow::GameplayObject;
+use crate::physics::Velocity;
+
+pub const SPLITTER: EnemyKind = EnemyKind::new("Splitter");
+pub const SHARD: EnemyKind = EnemyKind::new("Shard");
+
+// Slow and tough. Breaks into Shards when popped.
+pub struct SplitterBehavior;
+
+impl EnemyBehavior for SplitterBehavior {
+    fn kind(&self) -> EnemyKind {
+        SPLITTER
+    }
+
+    fn color(&self) -> Color {
+        LIME.into()
+    }
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        speed_multiplier: f32,
+    ) -> Vec2 {
+        random_direction(rng) * config.enemy_kind(&SPLITTER).speed * speed_multiplier
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing) {
+        let EnemyDrawing {
+            pos, scale, alpha, ..
+        } = *drawing;
+        let radius = drawing.enemy.radius;
+
+        // Outer shell - darken with damage
+        let shell_color: Color = Hsla::from(drawing.enemy.color)
+            .with_lightness(0.7 * drawing.health_factor)
+            .into();
+        gizmos.circle_2d(pos, radius * scale, shell_color.with_alpha(alpha));
+
+        // The shards it will break into, turning with its heading
+        let heading = drawing.velocity.to_angle();
+        for i in 0..3 {
+            let angle = heading + i as f32 * std::f32::consts::TAU / 3.0;
+            let lobe_pos = pos + Vec2::from_angle(angle) * radius * 0.45 * scale;
+            gizmos.circle_2d(lobe_pos, radius * 0.35 * scale, LIME.with_alpha(alpha));
+        }
+
+        // Core
+        gizmos.circle_2d(pos, radius * 0.15 * scale, Color::WHITE.with_alpha(alpha));
+    }
+
+    fn on_death(&self, death: &mut EnemyDeath) {
+        let color = death.enemy.color;
+        death.explode(ExplosionType::Enemy {
+            color: color.into(),
+        });
+        spawn_shards(
+            death.commands,
+            &mut death.rng.enemies,
+            death.config,
+            death.pos,
+            color,
+            death.speed_multiplier,
+        );
+    }
+}
+
+// Small and fast. Only released by a popped Splitter.
+pub struct ShardBehavior;
+
+impl EnemyBehavior for ShardBehavior {
+    fn kind(&self) -> EnemyKind {
+        SHARD
+    }
+
+    fn color(&self) -> Color {
+        LIME.into()
+    }
+
+    fn initial_velocity(
+        &self,
+        rng: &mut dyn RngCore,
+        config: &GameConfig,
+        speed_multiplier: f32,
+    ) -> Vec2 {
+        random_direction(rng) * config.enemy_kind(&SHARD).speed * speed_multiplier
+    }
+
+    fn draw(&self, gizmos: &mut Gizmos, drawing: &EnemyDrawing) {
+        let EnemyDrawing {
+            pos, scale, alpha, ..
+        } = *drawing;
+        let radius = drawing.enemy.radius;
+
+        // Diamond pointing along the heading
+        let forward = drawing.velocity.normalize_or(Vec2::Y) * radius * scale;
+        let side = forward.perp() * 0.6;
+        let points = [pos + forward, pos + side, pos - forward, pos - side];
+        for i in 0..points.len() {
+            let start = points[i];
+            let end = points[(i + 1) % points.len()];
+            gizmos.line_2d(start, end, drawing.enemy.color.with_alpha(alpha));
+        }
+
+        // Core
+        gizmos.circle_2d(pos, radius * 0.3 * scale, LIME.with_alpha(alpha));
+    }
+}
+
+// Break a popped Splitter into Shards flying apart. Shards skip `Growing` and
+// are dangerous right away.
+pub fn spawn_shards(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    config: &GameConfig,
+    pos: Vec2,
+    color: Color,
+    speed_multiplier: f32,
+) {
+    let splitter_config = config.enemy_kind(&SPLITTER);
+    let stats = ShardBehavior.stats(config);
+    let count = rng.gen_range(
+        splitter_config.min_children
+            ..=splitter_config
+                .max_children
+                .max(splitter_config.min_children),
+    );
+    let offset = rng.gen_range(0.0..std::f32::consts::TAU);
+
+    for i in 0..count {
+        let dir = Vec2::from_angle(offset + i as f32 * std::f32::consts::TAU / count as f32);
+        let shard_pos = pos + dir * stats.radius;
+
+        commands.spawn((
+            Enemy {
+                health: stats.health,
+                radius: stats.radius,
+                kind: SHARD,
+                color,
+                trap_hits: 0,
+            },
+            Transform::from_xyz(shard_pos.x, shard_pos.y, 0.0),
+            Velocity(dir * config.enemy_kind(&SHARD).speed * speed_multiplier),
+            Collider {
+                radius: stats.radius,
+                mass: stats.mass,
+                layer: CollisionLayer::Enemy,
+            },
+            GameplayObject,
+        ));
+    }
+}
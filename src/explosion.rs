@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::arena::Arena;
+use crate::config::{ExplosionConfig, GameConfig};
+use crate::game_flow::{is_playing_or_dying, GameSet, GameplayObject};
+use crate::physics::{drag_factor, interpolated_position, PreviousPosition};
+
+// Add explosion type enum
+#[derive(Component, Clone, Copy)]
+pub enum ExplosionType {
+    Ship,
+    Enemy { color: Srgba },
+    Boss,
+}
+
+impl ExplosionType {
+    fn config(self, config: &GameConfig) -> &ExplosionConfig {
+        match self {
+            ExplosionType::Ship => &config.ship_explosion,
+            ExplosionType::Enemy { .. } => &config.enemy_explosion,
+            ExplosionType::Boss => &config.boss_explosion,
+        }
+    }
+}
+
+// Add explosion particle component
+#[derive(Component)]
+#[require(PreviousPosition)]
+pub struct ExplosionParticle {
+    pub lifetime: Timer,
+    pub velocity: Vec2,
+    pub size: f32,
+}
+
+pub struct ExplosionPlugin;
+
+impl Plugin for ExplosionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            FixedUpdate,
+            update_explosion
+                .run_if(is_playing_or_dying)
+                .after(GameSet::Simulation),
+        )
+        .add_systems(Update, draw_explosion.in_set(GameSet::Draw));
+    }
+}
+
+// Add function to spawn explosions
+pub fn spawn_explosion(
+    commands: &mut Commands,
+    rng: &mut impl Rng,
+    config: &GameConfig,
+    pos: Vec2,
+    explosion_type: ExplosionType,
+) {
+    let explosion = explosion_type.config(config);
+
+    for _ in 0..explosion.particles {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let speed = rng.gen_range(explosion.min_speed..=explosion.max_speed);
+        let velocity = Vec2::new(angle.cos(), angle.sin()) * speed;
+
+        commands.spawn((
+            ExplosionParticle {
+                lifetime: Timer::from_seconds(explosion.lifetime, TimerMode::Once),
+                velocity,
+                size: rng.gen_range(explosion.min_size..=explosion.max_size),
+            },
+            Transform::from_xyz(pos.x, pos.y, 0.0),
+            explosion_type,
+            GameplayObject,
+        ));
+    }
+}
+
+// Update system to update explosion particles
+fn update_explosion(
+    mut commands: Commands,
+    mut particles: Query<(
+        Entity,
+        &mut Transform,
+        &mut ExplosionParticle,
+        &ExplosionType,
+    )>,
+    time: Res<Time>,
+    arena: Res<Arena>,
+    config: Res<GameConfig>,
+) {
+    for (entity, mut transform, mut particle, explosion_type) in &mut particles {
+        particle.lifetime.tick(time.delta());
+
+        if particle.lifetime.finished() {
+            commands.entity(entity).despawn();
+        } else {
+            transform.translation += particle.velocity.extend(0.0) * time.delta_secs();
+            arena.apply_topology(
+                &config.border,
+                &mut transform.translation,
+                &mut particle.velocity,
+            );
+            particle.velocity *=
+                drag_factor(explosion_type.config(&config).drag, time.delta_secs());
+        }
+    }
+}
+
+// Update draw_explosion to handle different explosion types
+fn draw_explosion(
+    mut gizmos: Gizmos,
+    query: Query<(
+        &Transform,
+        &PreviousPosition,
+        &ExplosionParticle,
+        &ExplosionType,
+    )>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    for (transform, previous, particle, explosion_type) in &query {
+        let pos = interpolated_position(transform, previous, &fixed_time);
+        let alpha = particle.lifetime.fraction_remaining();
+        let color = match explosion_type {
+            ExplosionType::Ship => Color::srgba(1.0, 0.5, 0.0, alpha),
+            ExplosionType::Enemy { color } => Color::from(*color).with_alpha(alpha),
+            // Burns from white hot to red as it fades
+            ExplosionType::Boss => Color::srgba(1.0, alpha, alpha * alpha, alpha),
+        };
+        gizmos.circle_2d(pos, particle.size, color);
+    }
+}
@@ -0,0 +1,247 @@
+use bevy::gizmos::config::GizmoConfigStore;
+use bevy::prelude::*;
+
+#[derive(States, Debug, Clone, Copy, Eq, PartialEq, Hash, Default)]
+pub enum GameState {
+    #[default]
+    Starting,
+    Playing,
+    Dying,
+    GameOver,
+}
+
+#[derive(Resource, Deref, DerefMut)]
+pub struct StartingTimer(pub Timer);
+
+// Gameplay systems tick in `FixedUpdate` during Playing; draw systems run every
+// frame and keep running while the ship explodes
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GameSet {
+    Simulation,
+    Draw,
+}
+
+// Steps of a simulation tick, in order. The collision pass and `CollisionSet`
+// run between `Move` and `Resolve`. Systems touching the same state, spawning
+// or despawning included, need a fixed order within a step, or a seeded run
+// won't play out the same way twice.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum SimulationSet {
+    Upkeep,  // Timers run down and waves advance
+    Control, // Player input turns into aim, thrust and weapon choice
+    Act,     // The ship fires, enemies spawn, steer and shoot
+    Move,    // Velocities are applied
+    Resolve, // Borders, damage, lifetimes and score once collisions are handled
+}
+
+// Add death timer resource
+#[derive(Resource, Deref, DerefMut)]
+pub struct DeathTimer(pub Timer);
+
+// Add marker component for gameplay entities
+#[derive(Component)]
+pub struct GameplayObject;
+
+// Add marker component for game over UI
+#[derive(Component)]
+struct GameOverUI;
+
+#[derive(Component)]
+struct ReplayButton;
+
+// Add marker component for the get ready text
+#[derive(Component)]
+struct GetReadyText;
+
+pub struct GameFlowPlugin;
+
+impl Plugin for GameFlowPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_state::<GameState>()
+            .insert_resource(StartingTimer(Timer::from_seconds(1.0, TimerMode::Once)))
+            .insert_resource(DeathTimer(Timer::from_seconds(1.0, TimerMode::Once)))
+            .insert_resource(ClearColor(Color::BLACK))
+            .configure_sets(
+                FixedUpdate,
+                GameSet::Simulation.run_if(in_state(GameState::Playing)),
+            )
+            .configure_sets(
+                FixedUpdate,
+                (
+                    SimulationSet::Upkeep,
+                    SimulationSet::Control,
+                    SimulationSet::Act,
+                    SimulationSet::Move,
+                    SimulationSet::Resolve,
+                )
+                    .chain()
+                    .in_set(GameSet::Simulation),
+            )
+            .configure_sets(
+                Update,
+                // Skip drawing when running without a renderer
+                GameSet::Draw
+                    .run_if(is_playing_or_dying)
+                    .run_if(resource_exists::<GizmoConfigStore>),
+            )
+            .add_systems(OnEnter(GameState::GameOver), spawn_game_over_ui)
+            .add_systems(OnExit(GameState::Dying), cleanup_gameplay)
+            .add_systems(
+                OnEnter(GameState::Starting),
+                (
+                    reset_starting_timer,
+                    reset_death_timer,
+                    spawn_get_ready_text,
+                ),
+            )
+            .add_systems(OnExit(GameState::Starting), cleanup_get_ready_text)
+            .add_systems(
+                Update,
+                handle_starting_state.run_if(in_state(GameState::Starting)),
+            )
+            .add_systems(
+                Update,
+                handle_replay_button.run_if(in_state(GameState::GameOver)),
+            )
+            .add_systems(
+                Update,
+                handle_death_timer.run_if(in_state(GameState::Dying)),
+            )
+            .add_systems(
+                Update,
+                handle_exit.run_if(resource_exists::<ButtonInput<KeyCode>>),
+            )
+            .add_systems(OnExit(GameState::GameOver), cleanup_game_over_ui);
+    }
+}
+
+// First, create a helper function for state conditions
+pub fn is_playing_or_dying(state: Res<State<GameState>>) -> bool {
+    matches!(state.get(), GameState::Playing | GameState::Dying)
+}
+
+// Add exit system
+fn handle_exit(keyboard: Res<ButtonInput<KeyCode>>, mut app_exit_events: EventWriter<AppExit>) {
+    if keyboard.just_pressed(KeyCode::Escape) {
+        app_exit_events.send(AppExit::default());
+    }
+}
+
+fn spawn_game_over_ui(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::NONE),
+            GameOverUI,
+        ))
+        .with_children(|parent| {
+            // Game Over Text
+            parent.spawn(Text::new("Game Over"));
+
+            // Replay Button
+            parent
+                .spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(150.0),
+                        height: Val::Px(50.0),
+                        margin: UiRect::all(Val::Px(20.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                    ReplayButton,
+                ))
+                .with_children(|parent| {
+                    parent.spawn(Text::new("Replay"));
+                });
+        });
+}
+
+fn handle_replay_button(
+    mut next_state: ResMut<NextState<GameState>>,
+    mut interaction_query: Query<&Interaction, (Changed<Interaction>, With<ReplayButton>)>,
+) {
+    for interaction in &mut interaction_query {
+        if *interaction == Interaction::Pressed {
+            next_state.set(GameState::Starting);
+        }
+    }
+}
+
+// Add cleanup system for game over UI
+fn cleanup_game_over_ui(mut commands: Commands, query: Query<Entity, With<GameOverUI>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+// Add cleanup system
+fn cleanup_gameplay(mut commands: Commands, query: Query<Entity, With<GameplayObject>>) {
+    for entity in &query {
+        commands.entity(entity).despawn();
+    }
+}
+
+fn handle_starting_state(
+    mut timer: ResMut<StartingTimer>,
+    mut next_state: ResMut<NextState<GameState>>,
+    time: Res<Time>,
+) {
+    timer.tick(time.delta());
+    if timer.finished() {
+        next_state.set(GameState::Playing);
+    }
+}
+
+// Add system to spawn the get ready text
+pub(crate) fn spawn_get_ready_text(mut commands: Commands) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                align_items: AlignItems::Center,
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            GetReadyText,
+        ))
+        .with_children(|parent| {
+            parent.spawn(Text::new("Get Ready!"));
+        });
+}
+
+// Add system to cleanup the get ready text
+fn cleanup_get_ready_text(mut commands: Commands, query: Query<Entity, With<GetReadyText>>) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+fn reset_starting_timer(mut starting_timer: ResMut<StartingTimer>) {
+    starting_timer.reset();
+}
+
+fn reset_death_timer(mut death_timer: ResMut<DeathTimer>) {
+    death_timer.reset();
+}
+
+fn handle_death_timer(
+    mut timer: ResMut<DeathTimer>,
+    time: Res<Time>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    timer.tick(time.delta());
+    if timer.finished() {
+        next_state.set(GameState::GameOver);
+    }
+}
@@ -0,0 +1,40 @@
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use std::time::Duration;
+
+use crate::{AudioPlugin, BubbleGamePlugins};
+
+// Runs the full game loop without a window, renderer or audio device. Drive
+// the game by writing `PlayerInput` and calling `App::update`; each update
+// advances time by a fixed step so runs are repeatable.
+pub struct HeadlessPlugins;
+
+impl PluginGroup for HeadlessPlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add_group(MinimalPlugins)
+            .add(StatesPlugin)
+            .add(HeadlessPlugin::default())
+            .add_group(BubbleGamePlugins.build().disable::<AudioPlugin>())
+    }
+}
+
+pub struct HeadlessPlugin {
+    pub timestep: Duration,
+}
+
+impl Default for HeadlessPlugin {
+    fn default() -> Self {
+        Self {
+            timestep: Duration::from_secs_f64(1.0 / 60.0),
+        }
+    }
+}
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(self.timestep));
+    }
+}
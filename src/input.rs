@@ -0,0 +1,122 @@
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+
+// Player intent for the current frame. Filled from keyboard and mouse when a
+// window is present; headless runs write it directly.
+#[derive(Resource, Debug, Default)]
+pub struct PlayerInput {
+    pub mouse_position: Vec2, // Cursor in world coordinates
+    pub mouse_fire: bool,
+    pub aim_keys: Vec2, // Combined WASD direction
+    pub aim_keys_pressed: bool,
+    pub thrust: Vec2,               // Desired thrust direction, zero when coasting
+    pub weapon_slot: Option<usize>, // Requested weapon, cleared once switched
+}
+
+// Keys that thrust the ship. Insert a different one to rebind.
+#[derive(Resource, Debug)]
+pub struct KeyBindings {
+    pub thrust_up: KeyCode,
+    pub thrust_down: KeyCode,
+    pub thrust_left: KeyCode,
+    pub thrust_right: KeyCode,
+}
+
+impl Default for KeyBindings {
+    fn default() -> Self {
+        Self {
+            thrust_up: KeyCode::ArrowUp,
+            thrust_down: KeyCode::ArrowDown,
+            thrust_left: KeyCode::ArrowLeft,
+            thrust_right: KeyCode::ArrowRight,
+        }
+    }
+}
+
+pub struct PlayerInputPlugin;
+
+impl Plugin for PlayerInputPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PlayerInput>()
+            .init_resource::<KeyBindings>()
+            .add_systems(
+                PreUpdate,
+                (read_keyboard_and_mouse, calculate_mouse_position)
+                    .after(InputSystem)
+                    .run_if(any_with_component::<Window>),
+            );
+    }
+}
+
+fn read_keyboard_and_mouse(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_button: Res<ButtonInput<MouseButton>>,
+    bindings: Res<KeyBindings>,
+    mut input: ResMut<PlayerInput>,
+) {
+    input.mouse_fire = mouse_button.pressed(MouseButton::Left);
+    input.aim_keys_pressed =
+        keyboard.any_pressed([KeyCode::KeyW, KeyCode::KeyA, KeyCode::KeyS, KeyCode::KeyD]);
+
+    let mut direction = Vec2::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        direction.y += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        direction.y -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        direction.x += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        direction.x -= 1.0;
+    }
+    input.aim_keys = direction;
+
+    let mut thrust = Vec2::ZERO;
+    if keyboard.pressed(bindings.thrust_up) {
+        thrust.y += 1.0;
+    }
+    if keyboard.pressed(bindings.thrust_down) {
+        thrust.y -= 1.0;
+    }
+    if keyboard.pressed(bindings.thrust_left) {
+        thrust.x -= 1.0;
+    }
+    if keyboard.pressed(bindings.thrust_right) {
+        thrust.x += 1.0;
+    }
+    input.thrust = thrust;
+
+    // Kept until the simulation picks it up, which may be a few frames later
+    let slots = [
+        KeyCode::Digit1,
+        KeyCode::Digit2,
+        KeyCode::Digit3,
+        KeyCode::Digit4,
+        KeyCode::Digit5,
+        KeyCode::Digit6,
+    ];
+    if let Some(slot) = slots.iter().position(|key| keyboard.just_pressed(*key)) {
+        input.weapon_slot = Some(slot);
+    }
+}
+
+fn calculate_mouse_position(
+    camera_query: Query<(&GlobalTransform, &Camera)>,
+    window_query: Query<&Window>,
+    mut input: ResMut<PlayerInput>,
+) {
+    let (Ok((camera_transform, camera)), Ok(window)) =
+        (camera_query.get_single(), window_query.get_single())
+    else {
+        return;
+    };
+
+    let position = window
+        .cursor_position()
+        .and_then(|cursor| camera.viewport_to_world_2d(camera_transform, cursor).ok())
+        .unwrap_or_default();
+
+    input.mouse_position = position;
+}
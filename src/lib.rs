@@ -0,0 +1,70 @@
+use bevy::app::PluginGroupBuilder;
+use bevy::prelude::*;
+
+pub mod arena;
+pub mod audio;
+pub mod bubble;
+pub mod collision;
+pub mod config;
+pub mod enemy;
+pub mod explosion;
+pub mod game_flow;
+pub mod headless;
+pub mod input;
+pub mod physics;
+pub mod pickup;
+pub mod projectile;
+pub mod rng;
+pub mod score;
+pub mod ship;
+pub mod spatial;
+pub mod wave;
+
+pub use arena::{Arena, ArenaPlugin};
+pub use audio::AudioPlugin;
+pub use bubble::{Arsenal, Bubble, BubblePlugin, BubbleWeapon};
+pub use collision::{Collider, CollisionLayer, CollisionPlugin};
+pub use config::{ConfigPlugin, GameConfig};
+pub use enemy::boss::{Boss, BossPlugin};
+pub use enemy::{
+    Enemy, EnemyBehavior, EnemyKind, EnemyPlugin, EnemyRegistry, RegisterEnemy, Trapped,
+};
+pub use explosion::ExplosionPlugin;
+pub use game_flow::{GameFlowPlugin, GameState};
+pub use headless::{HeadlessPlugin, HeadlessPlugins};
+pub use input::{KeyBindings, PlayerInput, PlayerInputPlugin};
+pub use physics::{PhysicsPlugin, Velocity};
+pub use pickup::{Pickup, PickupKind, PickupPlugin, PowerUps};
+pub use projectile::{Projectile, ProjectilePlugin};
+pub use rng::{GameRng, RngPlugin};
+pub use score::{Score, ScorePlugin};
+pub use ship::{Ship, ShipPlugin};
+pub use spatial::SpatialGrid;
+pub use wave::{WaveDirector, WavePlugin};
+
+// All gameplay plugins. Add on top of `DefaultPlugins`, or disable individual
+// subsystems with `BubbleGamePlugins.build().disable::<AudioPlugin>()`.
+pub struct BubbleGamePlugins;
+
+impl PluginGroup for BubbleGamePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::start::<Self>()
+            .add(GameFlowPlugin)
+            .add(RngPlugin::default())
+            .add(ConfigPlugin)
+            .add(ArenaPlugin)
+            .add(PlayerInputPlugin)
+            .add(PhysicsPlugin::default())
+            .add(CollisionPlugin)
+            .add(ShipPlugin)
+            .add(BubblePlugin)
+            .add(EnemyPlugin)
+            .add(BossPlugin)
+            .add(WavePlugin)
+            .add(ProjectilePlugin)
+            .add(PickupPlugin)
+            .add(ExplosionPlugin)
+            .add(ScorePlugin)
+            .add(AudioPlugin)
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use crate::game_flow::SimulationSet;
+
+// Skip interpolation for jumps longer than this, e.g. when wrapping around the
+// arena edge
+const MAX_INTERPOLATION_DISTANCE: f32 = 100.0;
+
+#[derive(Component, Default)]
+#[require(PreviousPosition)]
+pub struct Velocity(pub Vec2);
+
+// Moves a body at a fraction of its velocity until the timer runs out, e.g.
+// an enemy hit by a sticky bubble. The velocity itself is left alone, so
+// bounces and steering while slowed carry over once it wears off.
+#[derive(Component)]
+pub struct Slowed {
+    pub factor: f32,
+    pub timer: Timer,
+}
+
+// Position at the start of the current fixed tick, used to interpolate drawing
+// between ticks
+#[derive(Component, Default)]
+pub struct PreviousPosition(pub Vec2);
+
+pub struct PhysicsPlugin {
+    pub tick_rate: f64, // Simulation ticks per second
+}
+
+impl Default for PhysicsPlugin {
+    fn default() -> Self {
+        Self { tick_rate: 60.0 }
+    }
+}
+
+impl Plugin for PhysicsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Time::<Fixed>::from_hz(self.tick_rate))
+            .add_systems(FixedFirst, store_previous_positions)
+            .add_systems(FixedUpdate, apply_velocity.in_set(SimulationSet::Move))
+            .add_systems(FixedPostUpdate, init_previous_positions);
+    }
+}
+
+// Blend between the last two simulated positions based on how far we are into
+// the next tick
+pub fn interpolated_position(
+    transform: &Transform,
+    previous: &PreviousPosition,
+    fixed_time: &Time<Fixed>,
+) -> Vec2 {
+    let current = transform.translation.truncate();
+    if previous.0.distance(current) > MAX_INTERPOLATION_DISTANCE {
+        return current;
+    }
+    previous.0.lerp(current, fixed_time.overstep_fraction())
+}
+
+// Velocity multiplier for `delta_secs` of drag, given the fraction of velocity
+// kept over a whole second. Keeps coasting the same at any tick rate.
+pub fn drag_factor(kept_per_sec: f32, delta_secs: f32) -> f32 {
+    kept_per_sec.clamp(0.0, 1.0).powf(delta_secs)
+}
+
+fn store_previous_positions(mut query: Query<(&Transform, &mut PreviousPosition)>) {
+    for (transform, mut previous) in &mut query {
+        previous.0 = transform.translation.truncate();
+    }
+}
+
+// Entities spawned during a tick start out where they were spawned
+fn init_previous_positions(
+    mut query: Query<(&Transform, &mut PreviousPosition), Added<PreviousPosition>>,
+) {
+    for (transform, mut previous) in &mut query {
+        previous.0 = transform.translation.truncate();
+    }
+}
+
+fn apply_velocity(mut query: Query<(&mut Transform, &Velocity, Option<&Slowed>)>, time: Res<Time>) {
+    for (mut transform, velocity, slowed) in &mut query {
+        let factor = slowed.map_or(1.0, |slowed| slowed.factor);
+        transform.translation += velocity.0.extend(0.0) * factor * time.delta_secs()
+    }
+}
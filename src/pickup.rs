@@ -0,0 +1,384 @@
+use bevy::color::palettes::css::*;
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+use rand::Rng;
+use serde::Deserialize;
+
+use crate::collision::{Collider, CollisionLayer, CollisionSet, ShipPickupCollision};
+use crate::config::{GameConfig, PickupConfig};
+use crate::enemy::{destroy_dead_enemies, EnemyDestroyed, EnemyRegistry};
+use crate::game_flow::{GameSet, GameState, GameplayObject, SimulationSet};
+use crate::rng::GameRng;
+use crate::ship::Ship;
+
+#[derive(Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PickupKind {
+    RapidFire,  // Several bubbles per shot
+    WideSpread, // Wider bubble spread
+    FastRegen,  // Faster bubble supply regeneration
+    Shield,     // Blocks all damage
+    Repair,     // Restores health right away
+}
+
+impl PickupKind {
+    // Kinds that last for a while, in HUD order
+    const TIMED: [PickupKind; 4] = [
+        PickupKind::RapidFire,
+        PickupKind::WideSpread,
+        PickupKind::FastRegen,
+        PickupKind::Shield,
+    ];
+
+    fn color(self) -> Srgba {
+        match self {
+            PickupKind::RapidFire => ORANGE,
+            PickupKind::WideSpread => DEEP_SKY_BLUE,
+            PickupKind::FastRegen => LIME,
+            PickupKind::Shield => GOLD,
+            PickupKind::Repair => HOT_PINK,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            PickupKind::RapidFire => "Rapid fire",
+            PickupKind::WideSpread => "Wide spread",
+            PickupKind::FastRegen => "Fast regen",
+            PickupKind::Shield => "Shield",
+            PickupKind::Repair => "Repair",
+        }
+    }
+
+    fn icon(self) -> &'static str {
+        match self {
+            PickupKind::RapidFire => "R",
+            PickupKind::WideSpread => "W",
+            PickupKind::FastRegen => "F",
+            PickupKind::Shield => "S",
+            PickupKind::Repair => "+",
+        }
+    }
+
+    // Pick a kind by the weights in the config
+    pub fn random(rng: &mut impl Rng, config: &PickupConfig) -> Option<Self> {
+        let total: f32 = config
+            .weights
+            .iter()
+            .map(|(_, weight)| weight.max(0.0))
+            .sum();
+        if total <= 0.0 {
+            return None;
+        }
+
+        let mut pick = rng.gen_range(0.0..total);
+        for (kind, weight) in &config.weights {
+            let weight = weight.max(0.0);
+            if pick < weight {
+                return Some(*kind);
+            }
+            pick -= weight;
+        }
+        config.weights.last().map(|(kind, _)| *kind)
+    }
+}
+
+// Dropped by a destroyed enemy, waiting for the ship to fly through it
+#[derive(Component)]
+pub struct Pickup {
+    pub kind: PickupKind,
+    pub lifetime: Timer,
+}
+
+// Power-ups the ship has collected and how long each has left
+#[derive(Component, Default)]
+pub struct PowerUps {
+    active: Vec<(PickupKind, Timer)>,
+}
+
+impl PowerUps {
+    pub fn is_active(&self, kind: PickupKind) -> bool {
+        self.remaining(kind).is_some()
+    }
+
+    pub fn remaining(&self, kind: PickupKind) -> Option<f32> {
+        self.active
+            .iter()
+            .find(|(active, _)| *active == kind)
+            .map(|(_, timer)| timer.remaining_secs())
+    }
+
+    // Collecting a power-up that is already active restarts it
+    fn activate(&mut self, kind: PickupKind, duration: f32) {
+        let timer = Timer::from_seconds(duration, TimerMode::Once);
+        match self.active.iter_mut().find(|(active, _)| *active == kind) {
+            Some((_, active_timer)) => *active_timer = timer,
+            None => self.active.push((kind, timer)),
+        }
+    }
+}
+
+// Add pickup collected event
+#[derive(Event)]
+pub struct PickupCollected {
+    pub kind: PickupKind,
+}
+
+// Add power-up HUD
+#[derive(Component)]
+struct PowerUpRow(PickupKind);
+
+#[derive(Component)]
+struct PowerUpText(PickupKind);
+
+pub struct PickupPlugin;
+
+impl Plugin for PickupPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<PickupCollected>()
+            .add_systems(Startup, spawn_power_up_hud)
+            .add_systems(OnEnter(GameState::GameOver), hide_power_up_hud)
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_power_ups.in_set(SimulationSet::Upkeep),
+                    collect_pickups
+                        .in_set(CollisionSet)
+                        .after(destroy_dead_enemies),
+                    (drop_pickups, update_pickups)
+                        .chain()
+                        .in_set(SimulationSet::Resolve),
+                )
+                    .in_set(GameSet::Simulation),
+            )
+            .add_systems(
+                Update,
+                (draw_pickups, update_power_up_hud).in_set(GameSet::Draw),
+            );
+    }
+}
+
+pub(crate) fn drop_pickups(
+    mut commands: Commands,
+    mut enemy_destroyed: EventReader<EnemyDestroyed>,
+    mut game_rng: ResMut<GameRng>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    let pickup_config = &config.pickups;
+    let rng = &mut game_rng.pickups;
+
+    for event in enemy_destroyed.read() {
+        let drop_chance = registry
+            .get(&event.kind)
+            .map_or(pickup_config.drop_chance, |behavior| {
+                behavior.drop_chance(&config)
+            });
+        if !rng.gen_bool(drop_chance.clamp(0.0, 1.0) as f64) {
+            continue;
+        }
+        let Some(kind) = PickupKind::random(rng, pickup_config) else {
+            continue;
+        };
+
+        commands.spawn((
+            Pickup {
+                kind,
+                lifetime: Timer::from_seconds(pickup_config.lifetime, TimerMode::Once),
+            },
+            Transform::from_xyz(event.pos.x, event.pos.y, 0.0),
+            Collider {
+                radius: pickup_config.radius,
+                mass: 1.0,
+                layer: CollisionLayer::Pickup,
+            },
+            GameplayObject,
+        ));
+    }
+}
+
+pub(crate) fn collect_pickups(
+    mut commands: Commands,
+    mut collisions: EventReader<ShipPickupCollision>,
+    pickup_query: Query<&Pickup>,
+    mut ship_query: Query<(&mut Ship, &mut PowerUps)>,
+    mut pickup_collected: EventWriter<PickupCollected>,
+    config: Res<GameConfig>,
+) {
+    let mut collected = EntityHashSet::default();
+
+    for collision in collisions.read() {
+        if !collected.insert(collision.pickup) {
+            continue;
+        }
+        let Ok(pickup) = pickup_query.get(collision.pickup) else {
+            continue;
+        };
+        let Ok((mut ship, mut power_ups)) = ship_query.get_mut(collision.ship) else {
+            continue;
+        };
+
+        match pickup.kind {
+            PickupKind::Repair => {
+                ship.health = (ship.health + config.pickups.repair_amount).min(config.ship.health);
+            }
+            kind => power_ups.activate(kind, config.pickups.duration),
+        }
+
+        commands.entity(collision.pickup).despawn();
+        pickup_collected.send(PickupCollected { kind: pickup.kind });
+    }
+}
+
+fn update_pickups(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Pickup)>,
+    time: Res<Time>,
+) {
+    for (entity, mut pickup) in &mut query {
+        if pickup.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+fn update_power_ups(mut query: Query<&mut PowerUps>, time: Res<Time>) {
+    for mut power_ups in &mut query {
+        power_ups
+            .active
+            .retain_mut(|(_, timer)| !timer.tick(time.delta()).finished());
+    }
+}
+
+fn draw_pickups(mut gizmos: Gizmos, query: Query<(&Transform, &Pickup)>, config: Res<GameConfig>) {
+    let radius = config.pickups.radius;
+
+    for (transform, pickup) in &query {
+        // Bob gently, and blink during the last two seconds
+        let elapsed = pickup.lifetime.elapsed_secs();
+        let pos = transform.translation.truncate() + Vec2::Y * (elapsed * 3.0).sin() * 3.0;
+        let remaining = pickup.lifetime.remaining_secs();
+        let alpha = if remaining < 2.0 && ((remaining * 8.0) as u32).is_multiple_of(2) {
+            0.3
+        } else {
+            1.0
+        };
+        let color = pickup.kind.color().with_alpha(alpha);
+
+        gizmos.circle_2d(pos, radius, color);
+        gizmos.circle_2d(pos, radius * 0.8, color.with_alpha(alpha * 0.4));
+
+        // Symbol for the kind
+        let r = radius * 0.5;
+        match pickup.kind {
+            PickupKind::RapidFire => {
+                for offset in [-0.4, 0.4] {
+                    let tip = pos + Vec2::new(r * offset + r * 0.5, 0.0);
+                    gizmos.line_2d(tip + Vec2::new(-r * 0.6, r * 0.7), tip, color);
+                    gizmos.line_2d(tip + Vec2::new(-r * 0.6, -r * 0.7), tip, color);
+                }
+            }
+            PickupKind::WideSpread => {
+                let origin = pos - Vec2::X * r;
+                for angle in [-0.6, 0.0, 0.6] {
+                    gizmos.line_2d(origin, origin + Vec2::from_angle(angle) * r * 2.0, color);
+                }
+            }
+            PickupKind::FastRegen => {
+                gizmos.circle_2d(pos, r * 0.4, color);
+                gizmos.circle_2d(pos, r, color);
+            }
+            PickupKind::Shield => {
+                gizmos.arc_2d(
+                    Isometry2d::from_translation(pos),
+                    std::f32::consts::PI,
+                    r,
+                    color,
+                );
+                gizmos.line_2d(pos + Vec2::new(-r, 0.0), pos + Vec2::new(0.0, -r), color);
+                gizmos.line_2d(pos + Vec2::new(r, 0.0), pos + Vec2::new(0.0, -r), color);
+            }
+            PickupKind::Repair => {
+                gizmos.line_2d(pos - Vec2::X * r, pos + Vec2::X * r, color);
+                gizmos.line_2d(pos - Vec2::Y * r, pos + Vec2::Y * r, color);
+            }
+        }
+    }
+}
+
+// One row per timed power-up in the top right corner, hidden while inactive
+fn spawn_power_up_hud(mut commands: Commands) {
+    commands
+        .spawn(Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(10.0),
+            top: Val::Px(10.0),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|parent| {
+            for kind in PickupKind::TIMED {
+                parent
+                    .spawn((
+                        Node {
+                            align_items: AlignItems::Center,
+                            column_gap: Val::Px(6.0),
+                            ..default()
+                        },
+                        Visibility::Hidden,
+                        PowerUpRow(kind),
+                    ))
+                    .with_children(|parent| {
+                        // Icon
+                        parent
+                            .spawn((
+                                Node {
+                                    width: Val::Px(20.0),
+                                    height: Val::Px(20.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    ..default()
+                                },
+                                BackgroundColor(kind.color().into()),
+                            ))
+                            .with_children(|parent| {
+                                parent.spawn((
+                                    Text::new(kind.icon()),
+                                    TextFont::from_font_size(14.0),
+                                    TextColor(Color::BLACK),
+                                ));
+                            });
+
+                        parent.spawn((Text::new(""), PowerUpText(kind)));
+                    });
+            }
+        });
+}
+
+fn update_power_up_hud(
+    power_ups_query: Query<&PowerUps>,
+    mut row_query: Query<(&PowerUpRow, &mut Visibility)>,
+    mut text_query: Query<(&PowerUpText, &mut Text)>,
+) {
+    let power_ups = power_ups_query.get_single().ok();
+    let remaining = |kind: PickupKind| power_ups.and_then(|power_ups| power_ups.remaining(kind));
+
+    for (row, mut visibility) in &mut row_query {
+        *visibility = match remaining(row.0) {
+            Some(_) => Visibility::Inherited,
+            None => Visibility::Hidden,
+        };
+    }
+
+    for (text, mut value) in &mut text_query {
+        if let Some(remaining) = remaining(text.0) {
+            value.0 = format!("{} {}", text.0.label(), remaining.ceil());
+        }
+    }
+}
+
+fn hide_power_up_hud(mut row_query: Query<&mut Visibility, With<PowerUpRow>>) {
+    for mut visibility in &mut row_query {
+        *visibility = Visibility::Hidden;
+    }
+}
@@ -0,0 +1,197 @@
+use bevy::color::palettes::css::*;
+use bevy::ecs::entity::EntityHashSet;
+use bevy::prelude::*;
+
+use crate::arena::Arena;
+use crate::collision::{
+    BubbleProjectileCollision, Collider, CollisionLayer, CollisionSet, ShipProjectileCollision,
+};
+use crate::config::GameConfig;
+use crate::enemy::boss::update_bosses;
+use crate::enemy::{Growing, Trapped};
+use crate::game_flow::{GameSet, GameplayObject, SimulationSet};
+use crate::physics::{interpolated_position, PreviousPosition, Velocity};
+use crate::pickup::collect_pickups;
+use crate::ship::{handle_ship_enemy_collision, Ship, ShipBounced, ShipDamaged};
+
+// Fired by enemies at the ship
+#[derive(Component)]
+pub struct Projectile {
+    pub damage: f32,
+    pub lifetime: Timer,
+}
+
+// Lets an enemy fire projectiles at the ship
+#[derive(Component)]
+pub struct Gun {
+    pub timer: Timer,
+    pub aim: Vec2, // Direction of the last shot
+}
+
+impl Gun {
+    pub fn new(config: &GameConfig) -> Self {
+        Self {
+            timer: Timer::from_seconds(config.gun.fire_interval, TimerMode::Repeating),
+            aim: Vec2::Y,
+        }
+    }
+}
+
+// Add projectile popped event
+#[derive(Event)]
+pub struct ProjectilePopped;
+
+pub struct ProjectilePlugin;
+
+impl Plugin for ProjectilePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ProjectilePopped>()
+            .add_systems(
+                FixedUpdate,
+                (
+                    fire_projectiles
+                        .in_set(SimulationSet::Act)
+                        .after(update_bosses),
+                    update_projectiles
+                        .in_set(CollisionSet)
+                        .after(handle_ship_enemy_collision)
+                        .after(collect_pickups),
+                )
+                    .in_set(GameSet::Simulation),
+            )
+            .add_systems(Update, draw_projectiles.in_set(GameSet::Draw));
+    }
+}
+
+// Enemies can't shoot while they are still growing or trapped
+#[allow(clippy::type_complexity)]
+fn fire_projectiles(
+    mut commands: Commands,
+    mut gun_query: Query<(&Transform, &Collider, &mut Gun), (Without<Growing>, Without<Trapped>)>,
+    ship_query: Query<&Transform, With<Ship>>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    let Ok(ship_transform) = ship_query.get_single() else {
+        return;
+    };
+    let ship_pos = ship_transform.translation.truncate();
+
+    for (transform, collider, mut gun) in &mut gun_query {
+        if !gun.timer.tick(time.delta()).just_finished() {
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        gun.aim = (ship_pos - pos).normalize_or(gun.aim);
+        spawn_projectile(&mut commands, &config, pos, collider.radius, gun.aim);
+    }
+}
+
+// Fire a projectile from the edge of an enemy with the given radius. It
+// starts just outside so it doesn't hit the enemy itself.
+pub fn spawn_projectile(
+    commands: &mut Commands,
+    config: &GameConfig,
+    pos: Vec2,
+    radius: f32,
+    direction: Vec2,
+) {
+    let gun_config = &config.gun;
+    let start = pos + direction * (radius + gun_config.projectile_radius);
+
+    commands.spawn((
+        Projectile {
+            damage: gun_config.projectile_damage,
+            lifetime: Timer::from_seconds(gun_config.projectile_lifetime, TimerMode::Once),
+        },
+        Transform::from_xyz(start.x, start.y, 0.0),
+        Velocity(direction * gun_config.projectile_speed),
+        Collider {
+            radius: gun_config.projectile_radius,
+            mass: gun_config.projectile_mass,
+            layer: CollisionLayer::Projectile,
+        },
+        GameplayObject,
+    ));
+}
+
+// Resolve hits on the ship and by bubbles, then expire old projectiles. Each
+// projectile is removed at most once per tick.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn update_projectiles(
+    mut commands: Commands,
+    mut projectile_query: Query<(
+        Entity,
+        &mut Transform,
+        &mut Velocity,
+        &Collider,
+        &mut Projectile,
+    )>,
+    mut ship_query: Query<(&mut Velocity, &Collider), (With<Ship>, Without<Projectile>)>,
+    mut ship_collisions: EventReader<ShipProjectileCollision>,
+    mut bubble_collisions: EventReader<BubbleProjectileCollision>,
+    mut ship_damaged: EventWriter<ShipDamaged>,
+    mut ship_bounced: EventWriter<ShipBounced>,
+    mut projectile_popped: EventWriter<ProjectilePopped>,
+    time: Res<Time>,
+    arena: Res<Arena>,
+    config: Res<GameConfig>,
+) {
+    let mut removed = EntityHashSet::default();
+
+    // Hits on the ship go through the same damage path as any other contact
+    for collision in ship_collisions.read() {
+        if removed.contains(&collision.projectile) {
+            continue;
+        }
+        let Ok((_, _, velocity, collider, projectile)) = projectile_query.get(collision.projectile)
+        else {
+            continue;
+        };
+
+        if let Ok((mut ship_velocity, ship_collider)) = ship_query.get_mut(collision.ship) {
+            ship_velocity.0 += velocity.0 * collider.mass / ship_collider.mass;
+        }
+        ship_damaged.send(ShipDamaged {
+            amount: projectile.damage,
+        });
+        ship_bounced.send(ShipBounced);
+        removed.insert(collision.projectile);
+    }
+
+    // Bubbles pop projectiles and keep going
+    for collision in bubble_collisions.read() {
+        if removed.insert(collision.projectile) {
+            projectile_popped.send(ProjectilePopped);
+        }
+    }
+
+    for (entity, mut transform, mut velocity, _, mut projectile) in &mut projectile_query {
+        if removed.contains(&entity) {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        if projectile.lifetime.tick(time.delta()).finished() {
+            commands.entity(entity).despawn();
+        } else {
+            arena.apply_topology(&config.border, &mut transform.translation, &mut velocity.0);
+        }
+    }
+}
+
+fn draw_projectiles(
+    mut gizmos: Gizmos,
+    query: Query<(&Transform, &PreviousPosition, &Collider, &Projectile)>,
+    fixed_time: Res<Time<Fixed>>,
+) {
+    for (transform, previous, collider, projectile) in &query {
+        let pos = interpolated_position(transform, previous, &fixed_time);
+
+        // Fade out near the end of the lifetime
+        let alpha = (projectile.lifetime.remaining_secs() * 2.0).min(1.0);
+        gizmos.circle_2d(pos, collider.radius, RED.with_alpha(alpha));
+        gizmos.circle_2d(pos, collider.radius * 0.5, YELLOW.with_alpha(alpha));
+    }
+}
@@ -0,0 +1,48 @@
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+
+// All gameplay randomness comes from here. Each subsystem draws from its own
+// stream, so subsystems don't disturb each other's draws. Within a stream the
+// draws follow system and query order, which `SimulationSet` keeps fixed; the
+// same seed and the same inputs then replay the same game.
+#[derive(Resource)]
+pub struct GameRng {
+    seed: u64,
+    pub bubbles: StdRng,
+    pub enemies: StdRng,
+    pub effects: StdRng,
+    pub pickups: StdRng,
+}
+
+impl GameRng {
+    pub fn from_seed(seed: u64) -> Self {
+        let mut master = StdRng::seed_from_u64(seed);
+        Self {
+            seed,
+            bubbles: StdRng::seed_from_u64(master.next_u64()),
+            enemies: StdRng::seed_from_u64(master.next_u64()),
+            effects: StdRng::seed_from_u64(master.next_u64()),
+            pickups: StdRng::seed_from_u64(master.next_u64()),
+        }
+    }
+
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+// Seeds `GameRng`. Without a seed one is picked at random and logged so the
+// run can be reproduced later.
+#[derive(Default)]
+pub struct RngPlugin {
+    pub seed: Option<u64>,
+}
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        let seed = self.seed.unwrap_or_else(rand::random);
+        info!("Using RNG seed {seed}");
+        app.insert_resource(GameRng::from_seed(seed));
+    }
+}
@@ -0,0 +1,86 @@
+use bevy::prelude::*;
+
+use crate::config::GameConfig;
+use crate::enemy::{EnemyDestroyed, EnemyRegistry};
+use crate::game_flow::{GameSet, GameState, SimulationSet};
+
+// Update score resource
+#[derive(Resource, Default)]
+pub struct Score {
+    pub value: f32,
+    pub time_points: f32, // Points from surviving
+    pub kill_points: f32, // Points from destroying enemies
+}
+
+// Add score display
+#[derive(Component)]
+struct ScoreText;
+
+pub struct ScorePlugin;
+
+impl Plugin for ScorePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Score::default())
+            .add_event::<EnemyDestroyed>()
+            .add_systems(Startup, spawn_score_ui)
+            .add_systems(OnEnter(GameState::Starting), reset_score)
+            .add_systems(
+                FixedUpdate,
+                (update_score, handle_enemy_destroyed)
+                    .chain()
+                    .in_set(SimulationSet::Resolve),
+            )
+            .add_systems(Update, update_score_display.in_set(GameSet::Draw));
+    }
+}
+
+fn reset_score(mut score: ResMut<Score>) {
+    *score = Score::default();
+}
+
+// Update score systems
+fn update_score(mut score: ResMut<Score>, time: Res<Time>, config: Res<GameConfig>) {
+    let points = time.delta_secs() * config.score.points_per_second;
+    score.time_points += points;
+    score.value = score.time_points + score.kill_points;
+}
+
+fn handle_enemy_destroyed(
+    mut score: ResMut<Score>,
+    mut enemy_destroyed: EventReader<EnemyDestroyed>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    for event in enemy_destroyed.read() {
+        let mut points = registry
+            .get(&event.kind)
+            .map_or(config.score.points_per_enemy, |behavior| {
+                behavior.points(&config)
+            });
+        // Bonus for popping a trapped enemy
+        if event.popped {
+            points *= config.trap.pop_multiplier;
+        }
+        score.kill_points += points;
+        score.value = score.time_points + score.kill_points;
+    }
+}
+
+fn spawn_score_ui(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(10.0),
+            ..default()
+        },
+        ScoreText,
+        Text::new("Score: 0"),
+    ));
+}
+
+fn update_score_display(score: Res<Score>, mut query: Query<&mut Text, With<ScoreText>>) {
+    if let Ok(mut text) = query.get_single_mut() {
+        text.0 = format!("Score: {}", score.value as u32);
+    }
+}
@@ -0,0 +1,468 @@
+use bevy::prelude::*;
+
+use crate::arena::{Arena, Topology};
+use crate::bubble::Arsenal;
+use crate::collision::{
+    collision_impulse, exchange_momentum, Collider, CollisionLayer, CollisionSet,
+    ShipEnemyCollision,
+};
+use crate::config::GameConfig;
+use crate::enemy::{Enemy, Growing, Trapped};
+use crate::explosion::{spawn_explosion, ExplosionType};
+use crate::game_flow::{spawn_get_ready_text, GameSet, GameState, GameplayObject, SimulationSet};
+use crate::input::PlayerInput;
+use crate::physics::{drag_factor, interpolated_position, PreviousPosition, Velocity};
+use crate::pickup::{PickupKind, PowerUps};
+use crate::rng::GameRng;
+
+#[derive(Component)]
+#[require(Transform, Velocity, PowerUps, Arsenal)]
+pub struct Ship {
+    pub health: f32,
+    pub bubble_supply: f32, // 0.0 to 100.0
+}
+
+#[derive(PartialEq)]
+pub enum AimMode {
+    Mouse,
+    Keyboard,
+}
+
+// Update AimControl component
+#[derive(Component)]
+pub struct AimControl {
+    pub mode: AimMode,
+    pub angle: f32, // Angle in radians
+}
+
+impl Default for AimControl {
+    fn default() -> Self {
+        Self {
+            mode: AimMode::Mouse,
+            angle: 0.0, // Start aiming right
+        }
+    }
+}
+
+// Add shooting state component
+#[derive(Component)]
+pub struct ShootingState {
+    pub is_shooting: bool,
+    pub sound_timer: Timer,
+}
+
+impl Default for ShootingState {
+    fn default() -> Self {
+        Self {
+            is_shooting: false,
+            sound_timer: Timer::from_seconds(0.1, TimerMode::Repeating),
+        }
+    }
+}
+
+// Ship can't take damage until the timer runs out
+#[derive(Component)]
+pub struct Invulnerable {
+    pub timer: Timer,
+}
+
+// Add ship bounce event
+#[derive(Event)]
+pub struct ShipBounced;
+
+// Damage to the ship, applied unless it is invulnerable
+#[derive(Event)]
+pub struct ShipDamaged {
+    pub amount: f32,
+}
+
+pub struct ShipPlugin;
+
+impl Plugin for ShipPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ShipBounced>()
+            .add_event::<ShipDamaged>()
+            .add_systems(
+                OnEnter(GameState::Starting),
+                spawn_ship.after(spawn_get_ready_text),
+            )
+            .add_systems(OnEnter(GameState::Dying), spawn_ship_explosion)
+            .add_systems(
+                FixedUpdate,
+                (
+                    sync_ship_collider
+                        .run_if(resource_changed::<GameConfig>)
+                        .in_set(SimulationSet::Upkeep),
+                    (
+                        move_ship,
+                        (update_aim_control, update_shooting_state).chain(),
+                    )
+                        .in_set(SimulationSet::Control),
+                    handle_ship_enemy_collision.in_set(CollisionSet),
+                    (
+                        handle_ship_border,
+                        (update_invulnerability, apply_ship_damage, check_game_over)
+                            .chain()
+                            .after(handle_ship_border),
+                        regenerate_bubble_supply,
+                    )
+                        .in_set(SimulationSet::Resolve),
+                )
+                    .in_set(GameSet::Simulation),
+            )
+            .add_systems(Update, draw_ship.in_set(GameSet::Draw));
+    }
+}
+
+fn spawn_ship(mut commands: Commands, config: Res<GameConfig>) {
+    commands.spawn((
+        Ship {
+            health: config.ship.health,
+            bubble_supply: config.bubble.max_supply,
+        },
+        Transform::from_xyz(0.0, 0.0, 0.0),
+        Velocity::default(),
+        Collider {
+            radius: config.ship.radius,
+            mass: config.ship.mass,
+            layer: CollisionLayer::Ship,
+        },
+        AimControl::default(),
+        ShootingState::default(),
+        GameplayObject,
+    ));
+}
+
+// Update aim control system
+fn update_aim_control(mut query: Query<(&Transform, &mut AimControl)>, input: Res<PlayerInput>) {
+    if let Ok((transform, mut aim)) = query.get_single_mut() {
+        let pos = transform.translation.truncate();
+
+        // Check for mode switches
+        if input.mouse_fire {
+            aim.mode = AimMode::Mouse;
+        } else if input.aim_keys_pressed {
+            aim.mode = AimMode::Keyboard;
+        }
+
+        match aim.mode {
+            AimMode::Mouse => {
+                let to_mouse = input.mouse_position - pos;
+                aim.angle = Vec2::new(to_mouse.x, -to_mouse.y).angle_to(Vec2::X);
+            }
+            AimMode::Keyboard => {
+                if input.aim_keys != Vec2::ZERO {
+                    aim.angle = input.aim_keys.normalize().angle_to(Vec2::X);
+                }
+            }
+        }
+    }
+}
+
+// Update shooting state
+fn update_shooting_state(
+    mut query: Query<(&AimControl, &mut ShootingState)>,
+    input: Res<PlayerInput>,
+) {
+    if let Ok((aim, mut shooting)) = query.get_single_mut() {
+        shooting.is_shooting = match aim.mode {
+            AimMode::Mouse => input.mouse_fire,
+            AimMode::Keyboard => input.aim_keys_pressed,
+        };
+    }
+}
+
+// Pick up radius and mass changes from a reloaded config
+fn sync_ship_collider(mut query: Query<&mut Collider, With<Ship>>, config: Res<GameConfig>) {
+    if let Ok(mut collider) = query.get_single_mut() {
+        collider.radius = config.ship.radius;
+        collider.mass = config.ship.mass;
+    }
+}
+
+fn move_ship(
+    mut query: Query<&mut Velocity, With<Ship>>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+    input: Res<PlayerInput>,
+) {
+    if let Ok(mut velocity) = query.get_single_mut() {
+        let mut acceleration = input.thrust;
+        let dt = time.delta_secs();
+        let friction = drag_factor(config.ship.friction, dt);
+
+        if acceleration != Vec2::ZERO {
+            acceleration = acceleration.normalize() * config.ship.acceleration * dt;
+            velocity.0 += acceleration;
+        }
+
+        // Apply friction
+        velocity.0 *= friction;
+
+        // Clamp maximum speed
+        if velocity.0.length() > config.ship.max_speed {
+            velocity.0 = velocity.0.normalize() * config.ship.max_speed;
+        }
+
+        // Position itself is integrated by `apply_velocity`, like every other
+        // moving entity, and kept in the arena by `handle_ship_border`
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn draw_ship(
+    mut gizmos: Gizmos,
+    query: Query<(
+        &Transform,
+        &PreviousPosition,
+        &Ship,
+        &AimControl,
+        &PowerUps,
+        Option<&Invulnerable>,
+    )>,
+    arena: Res<Arena>,
+    time: Res<Time>,
+    fixed_time: Res<Time<Fixed>>,
+    config: Res<GameConfig>,
+) {
+    let border_width = config.border.width;
+
+    // Draw the arena edge the current topology bounces off
+    match config.border.topology {
+        Topology::Wrap => {}
+        Topology::Walls => {
+            gizmos.rect_2d(
+                Vec2::ZERO,
+                Vec2::new(arena.width, arena.height),
+                Color::srgba(1.0, 1.0, 1.0, 0.4),
+            );
+        }
+        Topology::Hazard => {
+            gizmos.rect_2d(
+                Vec2::ZERO,
+                Vec2::new(
+                    arena.width - 2.0 * border_width,
+                    arena.height - 2.0 * border_width,
+                ),
+                Color::srgba(1.0, 0.0, 0.0, 0.2),
+            );
+        }
+    }
+
+    if let Ok((transform, previous, ship, aim, power_ups, invulnerable)) = query.get_single() {
+        let pos = interpolated_position(transform, previous, &fixed_time);
+
+        // Calculate ship colors based on health
+        let health_factor = (ship.health / config.ship.health).clamp(0.0, 1.0);
+        let mut ship_color = Color::srgb(1.0, health_factor, health_factor);
+
+        // Blink while invulnerable
+        if let Some(invulnerable) = invulnerable {
+            let blink_on = ((invulnerable.timer.elapsed_secs() * 10.0) as u32).is_multiple_of(2);
+            if !blink_on {
+                ship_color = ship_color.with_alpha(0.2);
+            }
+        }
+
+        // Draw outer ship circle
+        gizmos.circle_2d(pos, config.ship.radius, ship_color);
+
+        // Draw shield ring while the power-up lasts
+        if power_ups.is_active(PickupKind::Shield) {
+            let pulse = (time.elapsed_secs() * 6.0).sin() * 0.2 + 0.6;
+            gizmos.circle_2d(
+                pos,
+                config.ship.radius + 6.0,
+                Color::srgba(1.0, 0.84, 0.0, pulse),
+            );
+        }
+
+        // Draw inner bubble supply circle
+        let bubble_radius = 10.0 * (ship.bubble_supply / config.bubble.max_supply);
+        let bubble_color = Color::srgb(0.3, 0.8, 1.0);
+        gizmos.circle_2d(pos, bubble_radius, bubble_color);
+
+        // Draw aim line using current aim angle
+        let aim_direction = Vec2::from_angle(aim.angle);
+        let rect_length = 20.0;
+        let rect_center = pos + aim_direction * 15.0;
+
+        // Draw aim line with different styles based on mode
+        match aim.mode {
+            AimMode::Mouse => {
+                gizmos.line_2d(
+                    rect_center - aim_direction * rect_length / 2.0,
+                    rect_center + aim_direction * rect_length / 2.0,
+                    ship_color,
+                );
+            }
+            AimMode::Keyboard => {
+                // Draw a dashed/animated line for keyboard mode
+                let segments = 5;
+                let segment_length = rect_length / segments as f32;
+                let time = (aim.angle / std::f32::consts::PI * 2.0).sin() * 0.5 + 0.5;
+
+                for i in 0..segments {
+                    let start = rect_center - aim_direction * rect_length / 2.0
+                        + aim_direction * i as f32 * segment_length;
+                    let end = start + aim_direction * segment_length * 0.7;
+                    let alpha = (i as f32 / segments as f32 + time) % 1.0;
+                    gizmos.line_2d(start, end, ship_color.with_alpha(alpha));
+                }
+            }
+        }
+    }
+}
+
+fn handle_ship_border(
+    mut ship_query: Query<(&mut Transform, &mut Velocity), With<Ship>>,
+    arena: Res<Arena>,
+    config: Res<GameConfig>,
+    mut ship_bounced: EventWriter<ShipBounced>,
+    mut ship_damaged: EventWriter<ShipDamaged>,
+) {
+    if let Ok((mut transform, mut velocity)) = ship_query.get_single_mut() {
+        let bounced =
+            arena.apply_topology(&config.border, &mut transform.translation, &mut velocity.0);
+        let hit_hazard = bounced && config.border.topology == Topology::Hazard;
+
+        if hit_hazard {
+            // Fixed damage on impact, and an extra push back into the arena
+            let to_center = -transform.translation.truncate().normalize_or_zero();
+            ship_damaged.send(ShipDamaged {
+                amount: config.border.damage,
+            });
+            velocity.0 += to_center * config.border.bounce_force;
+            ship_bounced.send(ShipBounced);
+        }
+    }
+}
+
+pub(crate) fn handle_ship_enemy_collision(
+    mut bodies: Query<(&mut Velocity, &Collider)>,
+    enemy_query: Query<Has<Trapped>, (With<Enemy>, Without<Growing>)>,
+    mut collisions: EventReader<ShipEnemyCollision>,
+    config: Res<GameConfig>,
+    mut ship_bounced: EventWriter<ShipBounced>,
+    mut ship_damaged: EventWriter<ShipDamaged>,
+) {
+    // Skip collision if enemy is still growing. Only handle one collision per
+    // tick.
+    let Some((collision, trapped)) = collisions.read().find_map(|collision| {
+        let trapped = enemy_query.get(collision.enemy).ok()?;
+        Some((*collision, trapped))
+    }) else {
+        return;
+    };
+    collisions.clear();
+
+    let Ok([(ship_velocity, ship_collider), (enemy_velocity, enemy_collider)]) =
+        bodies.get_many([collision.ship, collision.enemy])
+    else {
+        return;
+    };
+
+    // Bounce off the enemy, plus an extra kick that pushes the enemy back too
+    let mut impulse = collision_impulse(
+        (ship_velocity.0, ship_collider.mass),
+        (enemy_velocity.0, enemy_collider.mass),
+        collision.normal,
+        config.enemy.restitution,
+    );
+    if !trapped {
+        impulse += collision.normal * config.enemy.collision_force * ship_collider.mass;
+    }
+    exchange_momentum(&mut bodies, collision.ship, collision.enemy, impulse);
+    ship_bounced.send(ShipBounced);
+
+    // Trapped enemies are harmless, the ship just shoves them along
+    if !trapped {
+        ship_damaged.send(ShipDamaged {
+            amount: config.enemy.collision_damage,
+        });
+    }
+}
+
+fn update_invulnerability(
+    mut commands: Commands,
+    mut query: Query<(Entity, &mut Invulnerable)>,
+    time: Res<Time>,
+) {
+    for (entity, mut invulnerable) in &mut query {
+        if invulnerable.timer.tick(time.delta()).finished() {
+            commands.entity(entity).remove::<Invulnerable>();
+        }
+    }
+}
+
+// Take the largest hit of the tick, then ignore damage for a while so a
+// single contact only costs its damage once
+fn apply_ship_damage(
+    mut commands: Commands,
+    mut ship_query: Query<(Entity, &mut Ship, &PowerUps, Has<Invulnerable>)>,
+    mut ship_damaged: EventReader<ShipDamaged>,
+    config: Res<GameConfig>,
+) {
+    let Some(amount) = ship_damaged
+        .read()
+        .map(|damage| damage.amount)
+        .reduce(f32::max)
+    else {
+        return;
+    };
+
+    if let Ok((entity, mut ship, power_ups, invulnerable)) = ship_query.get_single_mut() {
+        if invulnerable || power_ups.is_active(PickupKind::Shield) {
+            return;
+        }
+        ship.health -= amount;
+        commands.entity(entity).insert(Invulnerable {
+            timer: Timer::from_seconds(config.ship.invulnerability_time, TimerMode::Once),
+        });
+    }
+}
+
+pub(crate) fn check_game_over(
+    ship_query: Query<&Ship>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if let Ok(ship) = ship_query.get_single() {
+        if ship.health <= 0.0 {
+            next_state.set(GameState::Dying);
+        }
+    }
+}
+
+// Add system to regenerate bubble supply
+fn regenerate_bubble_supply(
+    mut query: Query<(&mut Ship, &PowerUps)>,
+    time: Res<Time>,
+    config: Res<GameConfig>,
+) {
+    if let Ok((mut ship, power_ups)) = query.get_single_mut() {
+        let mut regen_rate = config.bubble.regen_rate;
+        if power_ups.is_active(PickupKind::FastRegen) {
+            regen_rate *= config.pickups.regen_multiplier;
+        }
+        ship.bubble_supply =
+            (ship.bubble_supply + regen_rate * time.delta_secs()).min(config.bubble.max_supply);
+    }
+}
+
+// Update ship explosion spawn to use the new system
+fn spawn_ship_explosion(
+    ship_query: Query<&Transform, With<Ship>>,
+    mut commands: Commands,
+    mut game_rng: ResMut<GameRng>,
+    config: Res<GameConfig>,
+) {
+    if let Ok(transform) = ship_query.get_single() {
+        spawn_explosion(
+            &mut commands,
+            &mut game_rng.effects,
+            &config,
+            transform.translation.truncate(),
+            ExplosionType::Ship,
+        );
+    }
+}
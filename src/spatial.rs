@@ -0,0 +1,68 @@
+use bevy::prelude::*;
+use bevy::utils::HashMap;
+
+// Roughly the diameter of an enemy, so most queries touch only a few cells
+pub const DEFAULT_CELL_SIZE: f32 = 64.0;
+
+// Uniform grid for finding entities near a point. The collision pass rebuilds
+// it from all colliders every tick.
+#[derive(Resource)]
+pub struct SpatialGrid {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<(Entity, Vec2)>>,
+    max_radius: f32,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: f32) -> Self {
+        Self {
+            cell_size,
+            cells: HashMap::default(),
+            max_radius: 0.0,
+        }
+    }
+
+    fn cell(&self, pos: Vec2) -> IVec2 {
+        (pos / self.cell_size).floor().as_ivec2()
+    }
+
+    // Empty all cells but keep their allocations for the next rebuild
+    pub fn clear(&mut self) {
+        for entries in self.cells.values_mut() {
+            entries.clear();
+        }
+        self.max_radius = 0.0;
+    }
+
+    pub fn insert(&mut self, entity: Entity, pos: Vec2, radius: f32) {
+        self.max_radius = self.max_radius.max(radius);
+        let cell = self.cell(pos);
+        self.cells.entry(cell).or_default().push((entity, pos));
+    }
+
+    // Largest radius inserted since the last clear. Widening a query by this
+    // much catches every body whose circle reaches into it.
+    pub fn max_radius(&self) -> f32 {
+        self.max_radius
+    }
+
+    // Entities within `radius` of `center`, in a stable order
+    pub fn query(&self, center: Vec2, radius: f32) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        let min = self.cell(center - Vec2::splat(radius));
+        let max = self.cell(center + Vec2::splat(radius));
+        let radius_squared = radius * radius;
+
+        (min.y..=max.y)
+            .flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .copied()
+            .filter(move |(_, pos)| pos.distance_squared(center) <= radius_squared)
+    }
+}
@@ -0,0 +1,305 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::arena::Arena;
+use crate::config::{GameConfig, SpawnPattern, WaveConfig, WavesConfig};
+use crate::enemy::boss::{Boss, BOSS};
+use crate::enemy::{spawn_enemy_warning, EnemyRegistry, SpawnWarning};
+use crate::game_flow::{GameSet, GameState, SimulationSet};
+use crate::rng::GameRng;
+use crate::ship::Ship;
+
+// Drives enemy spawning through the waves listed in the config
+#[derive(Resource, Default)]
+pub struct WaveDirector {
+    pub wave: u32,         // Current wave number, 0 before the first wave
+    pub elapsed_time: f32, // Seconds since the run started
+    phase: WavePhase,
+}
+
+enum WavePhase {
+    // Working through the wave's groups in order
+    Spawning {
+        group: usize,
+        spawned: u32,
+        cooldown: Timer,
+    },
+    // Waiting for the wave's boss to go down
+    Boss,
+    // Resting before the next wave
+    Breather(Timer),
+}
+
+impl Default for WavePhase {
+    fn default() -> Self {
+        WavePhase::Breather(Timer::default())
+    }
+}
+
+impl WaveDirector {
+    fn new(config: &WavesConfig) -> Self {
+        Self {
+            wave: 0,
+            elapsed_time: 0.0,
+            phase: WavePhase::Breather(Timer::from_seconds(config.start_delay, TimerMode::Once)),
+        }
+    }
+
+    // Seconds until the next wave starts, if resting between waves
+    pub fn breather_remaining(&self) -> Option<f32> {
+        match &self.phase {
+            WavePhase::Breather(timer) => Some(timer.remaining_secs()),
+            WavePhase::Spawning { .. } | WavePhase::Boss => None,
+        }
+    }
+
+    pub fn is_boss_fight(&self) -> bool {
+        matches!(self.phase, WavePhase::Boss)
+    }
+}
+
+fn is_boss_wave(config: &WavesConfig, wave: u32) -> bool {
+    config.boss_every > 0 && wave.is_multiple_of(config.boss_every)
+}
+
+// Look up a wave by number. Past the end of the list the last wave repeats
+// with its enemy counts scaled up.
+pub fn wave_config(config: &WavesConfig, wave: u32) -> Option<(&WaveConfig, f32)> {
+    let last = config.list.last()?;
+    let index = wave.saturating_sub(1) as usize;
+    match config.list.get(index) {
+        Some(wave_config) => Some((wave_config, 1.0)),
+        None => {
+            let repeats = (index + 1 - config.list.len()) as f32;
+            Some((last, 1.0 + repeats * config.repeat_count_growth))
+        }
+    }
+}
+
+// Add wave display
+#[derive(Component)]
+struct WaveText;
+
+pub struct WavePlugin;
+
+impl Plugin for WavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<WaveDirector>()
+            .add_systems(Startup, spawn_wave_ui)
+            .add_systems(OnEnter(GameState::Starting), reset_waves)
+            .add_systems(FixedUpdate, run_waves.in_set(SimulationSet::Upkeep))
+            .add_systems(Update, update_wave_display.in_set(GameSet::Draw));
+    }
+}
+
+fn reset_waves(mut director: ResMut<WaveDirector>, config: Res<GameConfig>) {
+    *director = WaveDirector::new(&config.waves);
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn run_waves(
+    mut commands: Commands,
+    mut director: ResMut<WaveDirector>,
+    mut game_rng: ResMut<GameRng>,
+    ship_query: Query<&Transform, With<Ship>>,
+    boss_query: Query<(), With<Boss>>,
+    warning_query: Query<&SpawnWarning>,
+    time: Res<Time>,
+    arena: Res<Arena>,
+    registry: Res<EnemyRegistry>,
+    config: Res<GameConfig>,
+) {
+    director.elapsed_time += time.delta_secs();
+    let wave = director.wave;
+    let rng = &mut game_rng.enemies;
+
+    match &mut director.phase {
+        WavePhase::Breather(timer) => {
+            if timer.tick(time.delta()).finished() {
+                director.wave += 1;
+                director.phase = WavePhase::Spawning {
+                    group: 0,
+                    spawned: 0,
+                    cooldown: Timer::default(),
+                };
+            }
+        }
+        WavePhase::Spawning {
+            group,
+            spawned,
+            cooldown,
+        } => {
+            if !cooldown.tick(time.delta()).finished() {
+                return;
+            }
+
+            let Some((wave_config, count_scale)) = wave_config(&config.waves, wave) else {
+                return;
+            };
+
+            // Calculate spawn area within borders. Margins larger than the
+            // arena collapse the area to the center instead of an empty range.
+            let margin = config.border.width + config.enemy.spawn_margin;
+            let area = SpawnArea {
+                half_size: (arena.half_size() - Vec2::splat(margin)).max(Vec2::ZERO),
+                ship_pos: ship_query
+                    .get_single()
+                    .map(|transform| transform.translation.truncate())
+                    .ok(),
+                min_distance: config.enemy.min_spawn_distance,
+            };
+
+            let Some(wave_group) = wave_config.groups.get(*group) else {
+                director.phase = if is_boss_wave(&config.waves, wave) {
+                    let pos = area.random_point(rng);
+                    spawn_enemy_warning(&mut commands, &config, BOSS, pos);
+                    WavePhase::Boss
+                } else {
+                    WavePhase::Breather(Timer::from_seconds(wave_config.breather, TimerMode::Once))
+                };
+                return;
+            };
+
+            let count = (wave_group.count as f32 * count_scale).round() as u32;
+
+            let positions = match wave_group.pattern {
+                SpawnPattern::Random => vec![area.random_point(rng)],
+                SpawnPattern::Ring => {
+                    let radius = area.half_size.min_element();
+                    let offset = rng.gen_range(0.0..std::f32::consts::TAU);
+                    (0..count)
+                        .map(|i| {
+                            let angle = offset + i as f32 * std::f32::consts::TAU / count as f32;
+                            area.keep_away(Vec2::from_angle(angle) * radius, rng)
+                        })
+                        .collect()
+                }
+                SpawnPattern::Cluster => {
+                    let center = area.random_point(rng);
+                    let spread = config.enemy.cluster_spread;
+                    (0..count)
+                        .map(|_| {
+                            let offset = Vec2::new(
+                                rng.gen_range(-spread..=spread),
+                                rng.gen_range(-spread..=spread),
+                            );
+                            area.keep_away(center + offset, rng)
+                        })
+                        .collect()
+                }
+            };
+
+            for pos in positions
+                .into_iter()
+                .take(count.saturating_sub(*spawned) as usize)
+            {
+                // Groups without a kind mix in whatever the registry offers
+                let kind = match &wave_group.kind {
+                    Some(kind) => Some(kind.clone()),
+                    None => registry.random_kind(rng, &config),
+                };
+                if let Some(kind) = kind {
+                    spawn_enemy_warning(&mut commands, &config, kind, pos);
+                }
+                *spawned += 1;
+            }
+
+            *cooldown = Timer::from_seconds(wave_group.interval, TimerMode::Once);
+            if *spawned >= count {
+                *group += 1;
+                *spawned = 0;
+            }
+        }
+        WavePhase::Boss => {
+            let boss_pending = warning_query.iter().any(|warning| warning.kind == BOSS);
+            if boss_query.is_empty() && !boss_pending {
+                let breather = wave_config(&config.waves, wave)
+                    .map_or(0.0, |(wave_config, _)| wave_config.breather);
+                director.phase =
+                    WavePhase::Breather(Timer::from_seconds(breather, TimerMode::Once));
+            }
+        }
+    }
+}
+
+// Where enemies may appear: inside the borders and away from the ship
+struct SpawnArea {
+    half_size: Vec2,
+    ship_pos: Option<Vec2>,
+    min_distance: f32,
+}
+
+impl SpawnArea {
+    const MAX_TRIES: usize = 16;
+
+    // Uniform point that is far enough from the ship. If none of the tries
+    // is, the one farthest from the ship wins.
+    fn random_point(&self, rng: &mut impl Rng) -> Vec2 {
+        let mut best = Vec2::ZERO;
+        let mut best_distance = f32::NEG_INFINITY;
+
+        for _ in 0..Self::MAX_TRIES {
+            let point = Vec2::new(
+                rng.gen_range(-self.half_size.x..=self.half_size.x),
+                rng.gen_range(-self.half_size.y..=self.half_size.y),
+            );
+            let Some(ship_pos) = self.ship_pos else {
+                return point;
+            };
+
+            let distance = point.distance(ship_pos);
+            if distance >= self.min_distance {
+                return point;
+            }
+            if distance > best_distance {
+                best = point;
+                best_distance = distance;
+            }
+        }
+
+        best
+    }
+
+    // Push a point out of the ship's safe radius and back inside the area. If
+    // the edge pushes it back in too close, fall back to a random point.
+    fn keep_away(&self, point: Vec2, rng: &mut impl Rng) -> Vec2 {
+        let Some(ship_pos) = self.ship_pos else {
+            return point.clamp(-self.half_size, self.half_size);
+        };
+
+        let point = if point.distance(ship_pos) < self.min_distance {
+            let away = (point - ship_pos).normalize_or(Vec2::Y);
+            ship_pos + away * self.min_distance
+        } else {
+            point
+        };
+        let point = point.clamp(-self.half_size, self.half_size);
+        if point.distance(ship_pos) < self.min_distance {
+            return self.random_point(rng);
+        }
+        point
+    }
+}
+
+fn spawn_wave_ui(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(10.0),
+            top: Val::Px(40.0),
+            ..default()
+        },
+        WaveText,
+        Text::new(""),
+    ));
+}
+
+fn update_wave_display(director: Res<WaveDirector>, mut query: Query<&mut Text, With<WaveText>>) {
+    if let Ok(mut text) = query.get_single_mut() {
+        text.0 = match director.breather_remaining() {
+            Some(remaining) => format!("Wave {} in {}", director.wave + 1, remaining.ceil()),
+            None if director.is_boss_fight() => format!("Wave {} - Boss", director.wave),
+            None => format!("Wave {}", director.wave),
+        };
+    }
+}
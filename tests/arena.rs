@@ -0,0 +1,81 @@
+use bevy::prelude::*;
+use bubble::arena::Topology;
+use bubble::config::BorderConfig;
+use bubble::Arena;
+
+// 200 by 100, so the edges sit at x = ±100 and y = ±50
+const ARENA: Arena = Arena {
+    width: 200.0,
+    height: 100.0,
+};
+
+fn border(topology: Topology) -> BorderConfig {
+    BorderConfig {
+        topology,
+        width: 10.0,
+        ..default()
+    }
+}
+
+// Apply the topology once and return the new position, velocity and whether
+// it bounced
+fn step(topology: Topology, position: Vec2, velocity: Vec2) -> (Vec2, Vec2, bool) {
+    let mut position = position.extend(5.0);
+    let mut velocity = velocity;
+    let bounced = ARENA.apply_topology(&border(topology), &mut position, &mut velocity);
+    assert_eq!(position.z, 5.0, "depth must be kept");
+    (position.truncate(), velocity, bounced)
+}
+
+#[test]
+fn wrap_moves_bodies_to_the_opposite_side() {
+    let velocity = Vec2::new(30.0, -20.0);
+    assert_eq!(
+        step(Topology::Wrap, Vec2::new(105.0, -55.0), velocity),
+        (Vec2::new(-95.0, 45.0), velocity, false)
+    );
+    assert_eq!(
+        step(Topology::Wrap, Vec2::new(-101.0, 0.0), velocity),
+        (Vec2::new(99.0, 0.0), velocity, false)
+    );
+}
+
+#[test]
+fn walls_bounce_bodies_back_inside() {
+    // Leaving on both axes: turned around on both and put back on the edge
+    assert_eq!(
+        step(
+            Topology::Walls,
+            Vec2::new(110.0, -60.0),
+            Vec2::new(30.0, -20.0)
+        ),
+        (Vec2::new(100.0, -50.0), Vec2::new(-30.0, 20.0), true)
+    );
+    // Already heading back in: left alone apart from the clamp
+    assert_eq!(
+        step(
+            Topology::Walls,
+            Vec2::new(110.0, 0.0),
+            Vec2::new(-30.0, 0.0)
+        ),
+        (Vec2::new(100.0, 0.0), Vec2::new(-30.0, 0.0), false)
+    );
+}
+
+#[test]
+fn hazard_bounces_off_the_border_zone() {
+    // Inside the border zone and heading out: turned around but not moved
+    assert_eq!(
+        step(Topology::Hazard, Vec2::new(95.0, 0.0), Vec2::new(30.0, 5.0)),
+        (Vec2::new(95.0, 0.0), Vec2::new(-30.0, 5.0), true)
+    );
+    // Short of the zone nothing happens
+    assert_eq!(
+        step(
+            Topology::Hazard,
+            Vec2::new(85.0, 35.0),
+            Vec2::new(30.0, 5.0)
+        ),
+        (Vec2::new(85.0, 35.0), Vec2::new(30.0, 5.0), false)
+    );
+}
@@ -0,0 +1,102 @@
+use bevy::prelude::*;
+use bubble::{
+    Bubble, BubbleWeapon, Collider, GameConfig, GameState, HeadlessPlugins, PlayerInput, RngPlugin,
+    Ship,
+};
+
+// No regeneration, so every bit of supply used shows up
+const CONFIG: &str = "(
+    waves: (list: []),
+    bubble: (regen_rate: 0.0),
+    weapons: (charge: (min_size: 5.0, max_size: 15.0, growth_rate: 20.0, cost_per_size: 1.0)),
+)";
+
+// A running round with the charge weapon selected and `supply` to spend
+fn charging_app(supply: f32) -> App {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(23) }));
+    app.insert_resource(ron::de::from_str::<GameConfig>(CONFIG).unwrap());
+    while *app.world().resource::<State<GameState>>().get() != GameState::Playing {
+        app.update();
+    }
+
+    app.world_mut().resource_mut::<PlayerInput>().weapon_slot = Some(5);
+    app.update();
+    app.world_mut()
+        .query::<&mut Ship>()
+        .single_mut(app.world_mut())
+        .bubble_supply = supply;
+    app
+}
+
+fn hold_fire(app: &mut App, held: bool, updates: u32) {
+    let mut input = app.world_mut().resource_mut::<PlayerInput>();
+    input.aim_keys = Vec2::X;
+    input.aim_keys_pressed = held;
+    for _ in 0..updates {
+        app.update();
+    }
+}
+
+fn supply(app: &mut App) -> f32 {
+    app.world_mut()
+        .query::<&Ship>()
+        .single(app.world())
+        .bubble_supply
+}
+
+fn charge_bubbles(app: &mut App) -> Vec<(f32, Option<f32>)> {
+    app.world_mut()
+        .query::<(&Bubble, Option<&Collider>)>()
+        .iter(app.world())
+        .filter(|(bubble, _)| bubble.weapon == BubbleWeapon::Charge)
+        .map(|(bubble, collider)| (bubble.size, collider.map(|collider| collider.radius)))
+        .collect()
+}
+
+fn assert_near(actual: f32, expected: f32) {
+    assert!((actual - expected).abs() < 1e-3, "{actual} != {expected}");
+}
+
+#[test]
+fn charge_stops_growing_at_max_size() {
+    let mut app = charging_app(100.0);
+
+    // Long enough to grow well past the maximum
+    hold_fire(&mut app, true, 120);
+    let [(size, None)] = charge_bubbles(&mut app)[..] else {
+        panic!("expected one charging bubble");
+    };
+    assert_near(size, 15.0);
+    assert_near(supply(&mut app), 85.0);
+
+    // Released at full size, and collides at that size
+    hold_fire(&mut app, false, 3);
+    let [(size, Some(radius))] = charge_bubbles(&mut app)[..] else {
+        panic!("expected one launched bubble");
+    };
+    assert_near(size, 15.0);
+    assert_eq!(radius, size);
+}
+
+#[test]
+fn charge_stops_growing_when_supply_runs_out() {
+    let mut app = charging_app(8.0);
+
+    hold_fire(&mut app, true, 120);
+    let [(size, None)] = charge_bubbles(&mut app)[..] else {
+        panic!("expected one charging bubble");
+    };
+    assert_near(size, 8.0);
+    assert_near(supply(&mut app), 0.0);
+    assert!(supply(&mut app) >= 0.0);
+}
+
+#[test]
+fn charge_needs_supply_for_its_starting_size() {
+    let mut app = charging_app(4.0);
+
+    hold_fire(&mut app, true, 60);
+    assert!(charge_bubbles(&mut app).is_empty());
+    assert_eq!(supply(&mut app), 4.0);
+}
@@ -0,0 +1,53 @@
+use bubble::enemy::seeker::SEEKER;
+use bubble::enemy::splitter::SPLITTER;
+use bubble::GameConfig;
+
+#[test]
+fn balance_file_matches_defaults() {
+    let text = std::fs::read_to_string("assets/balance.config.ron").unwrap();
+    let loaded: GameConfig = ron::de::from_str(&text).unwrap();
+    assert_eq!(
+        format!("{loaded:#?}"),
+        format!("{:#?}", GameConfig::default())
+    );
+}
+
+#[test]
+fn enemy_kind_fields_fall_back_to_defaults() {
+    let loaded: GameConfig = ron::de::from_str("(enemies: { Seeker: (health: 10.0) })").unwrap();
+    let seeker = loaded.enemy_kind(&SEEKER);
+    assert_eq!(seeker.health, 10.0);
+    assert!(seeker.turn_rate > 0.0);
+}
+
+#[test]
+fn misspelled_enemy_kind_field_is_an_error() {
+    let result = ron::de::from_str::<GameConfig>("(enemies: { Seeker: (trun_rate: 3.0) })");
+    assert!(result.is_err());
+}
+
+#[test]
+fn validate_fixes_values_that_would_panic() {
+    let mut config: GameConfig = ron::de::from_str(
+        "(
+            waves: (start_delay: -2.0, list: [(breather: -1.0, groups: [(interval: -0.5)])]),
+            trap: (duration: -5.0),
+            bubble: (min_speed: 200.0, max_speed: 100.0),
+            enemies: { Splitter: (min_children: 4, max_children: 1) },
+        )",
+    )
+    .unwrap();
+    config.validate();
+
+    assert_eq!(config.waves.start_delay, 2.0);
+    assert_eq!(config.waves.list[0].breather, 1.0);
+    assert_eq!(config.waves.list[0].groups[0].interval, 0.5);
+    assert_eq!(config.trap.duration, 5.0);
+    assert_eq!(
+        (config.bubble.min_speed, config.bubble.max_speed),
+        (100.0, 200.0)
+    );
+
+    let splitter = config.enemy_kind(&SPLITTER);
+    assert_eq!((splitter.min_children, splitter.max_children), (1, 4));
+}
@@ -0,0 +1,121 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bubble::enemy::floater::FLOATER;
+use bubble::enemy::spawn_enemy;
+use bubble::game_flow::GameplayObject;
+use bubble::{
+    Bubble, BubbleWeapon, Collider, CollisionLayer, Enemy, EnemyRegistry, GameConfig, GameState,
+    HeadlessPlugins, RngPlugin, Velocity,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const ENEMY_POS: Vec2 = Vec2::new(200.0, 0.0);
+
+fn bubble(weapon: BubbleWeapon, size: f32) -> Bubble {
+    Bubble {
+        weapon,
+        color: Color::WHITE,
+        size,
+        lifetime: Timer::from_seconds(10.0, TimerMode::Once),
+    }
+}
+
+// A running round with no waves, so the only enemy is the one placed here
+fn playing_app_with_enemy() -> App {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(3) }));
+    app.insert_resource(ron::de::from_str::<GameConfig>("(waves: (list: []))").unwrap());
+    while *app.world().resource::<State<GameState>>().get() != GameState::Playing {
+        app.update();
+    }
+
+    app.world_mut()
+        .run_system_once(
+            |mut commands: Commands, registry: Res<EnemyRegistry>, config: Res<GameConfig>| {
+                let behavior = registry.get(&FLOATER).unwrap();
+                let mut rng = StdRng::seed_from_u64(0);
+                spawn_enemy(&mut commands, &mut rng, &config, behavior, ENEMY_POS, 1.0);
+            },
+        )
+        .unwrap();
+    app
+}
+
+fn spawn_bubble(app: &mut App, pos: Vec2, bubble: Bubble) {
+    let radius = bubble.size;
+    app.world_mut().spawn((
+        bubble,
+        Transform::from_xyz(pos.x, pos.y, 0.0),
+        Velocity(Vec2::ZERO),
+        Collider {
+            radius,
+            mass: 1.0,
+            layer: CollisionLayer::Bubble,
+        },
+        GameplayObject,
+    ));
+}
+
+fn enemy_health(app: &mut App) -> f32 {
+    app.world_mut().query::<&Enemy>().single(app.world()).health
+}
+
+#[test]
+fn damage_grows_with_size_for_standard_and_charge_bubbles() {
+    let config = GameConfig::default();
+
+    for weapon in [BubbleWeapon::Standard, BubbleWeapon::Charge] {
+        let small = bubble(weapon, 5.0).damage(&config);
+        let big = bubble(weapon, 10.0).damage(&config);
+        assert!(small > 0.0);
+        assert_eq!(big, small * 2.0, "{weapon:?}");
+    }
+    assert_eq!(
+        bubble(BubbleWeapon::Standard, 7.0).damage(&config),
+        7.0 * config.bubble.damage_per_size
+    );
+
+    // The rest deal a flat amount whatever their size
+    for weapon in [
+        BubbleWeapon::Sticky,
+        BubbleWeapon::Heavy,
+        BubbleWeapon::Shotgun,
+    ] {
+        assert_eq!(
+            bubble(weapon, 5.0).damage(&config),
+            bubble(weapon, 10.0).damage(&config),
+            "{weapon:?}"
+        );
+    }
+}
+
+#[test]
+fn bubble_hit_takes_its_damage_off_the_enemy() {
+    let mut app = playing_app_with_enemy();
+    let full_health = app
+        .world()
+        .resource::<GameConfig>()
+        .enemy_kind(&FLOATER)
+        .health;
+    let hit = bubble(BubbleWeapon::Standard, 10.0);
+    let damage = hit.damage(app.world().resource::<GameConfig>());
+    assert!(damage < full_health);
+
+    // One bubble well out of reach, one right on top of the enemy
+    spawn_bubble(
+        &mut app,
+        ENEMY_POS + Vec2::new(0.0, 150.0),
+        bubble(BubbleWeapon::Standard, 10.0),
+    );
+    spawn_bubble(&mut app, ENEMY_POS, hit);
+    for _ in 0..3 {
+        app.update();
+    }
+
+    assert_eq!(enemy_health(&mut app), full_health - damage);
+    assert_eq!(
+        app.world_mut().query::<&Bubble>().iter(app.world()).count(),
+        1
+    );
+}
@@ -0,0 +1,51 @@
+use bevy::prelude::*;
+use bubble::{Bubble, Enemy, HeadlessPlugins, PlayerInput, RngPlugin, Score, WaveDirector};
+
+const SEED: u64 = 42;
+const TICKS: u32 = 60 * 30;
+
+#[derive(Debug, PartialEq)]
+struct Snapshot {
+    score: f32,
+    wave: u32,
+    enemies: Vec<Vec2>,
+    bubbles: Vec<Vec2>,
+}
+
+fn positions<T: Component>(app: &mut App) -> Vec<Vec2> {
+    app.world_mut()
+        .query_filtered::<&Transform, With<T>>()
+        .iter(app.world())
+        .map(|transform| transform.translation.truncate())
+        .collect()
+}
+
+// Spin the aim and fire constantly, like the headless example
+fn run(seed: u64) -> Snapshot {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(seed) }));
+
+    for tick in 0..TICKS {
+        let mut input = app.world_mut().resource_mut::<PlayerInput>();
+        input.aim_keys = Vec2::from_angle(tick as f32 * 0.05);
+        input.aim_keys_pressed = true;
+        app.update();
+    }
+
+    Snapshot {
+        score: app.world().resource::<Score>().value,
+        wave: app.world().resource::<WaveDirector>().wave,
+        enemies: positions::<Enemy>(&mut app),
+        bubbles: positions::<Bubble>(&mut app),
+    }
+}
+
+#[test]
+fn same_seed_plays_out_the_same() {
+    let first = run(SEED);
+    assert!(
+        !first.enemies.is_empty() && !first.bubbles.is_empty(),
+        "nothing left to compare: {first:?}"
+    );
+    assert_eq!(first, run(SEED));
+}
@@ -0,0 +1,67 @@
+use bevy::prelude::*;
+use bubble::game_flow::GameplayObject;
+use bubble::{GameState, HeadlessPlugins, RngPlugin, Ship};
+
+const MAX_FRAMES: u32 = 60 * 10;
+
+fn state(app: &App) -> GameState {
+    *app.world().resource::<State<GameState>>().get()
+}
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state);
+}
+
+// Update until the game reaches `target`, failing if it takes too long
+fn run_until(app: &mut App, target: GameState) {
+    for _ in 0..MAX_FRAMES {
+        if state(app) == target {
+            return;
+        }
+        app.update();
+    }
+    panic!("never reached {target:?}, stuck in {:?}", state(app));
+}
+
+fn count<F: bevy::ecs::query::QueryFilter>(app: &mut App) -> usize {
+    app.world_mut()
+        .query_filtered::<(), F>()
+        .iter(app.world())
+        .count()
+}
+
+#[test]
+fn round_runs_from_starting_back_to_starting() {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(7) }));
+    app.update();
+    assert_eq!(state(&app), GameState::Starting);
+
+    for _ in 0..2 {
+        run_until(&mut app, GameState::Playing);
+        assert_eq!(count::<With<Ship>>(&mut app), 1);
+
+        // Play for a while so waves, bubbles and enemies are all in flight
+        for _ in 0..60 * 5 {
+            app.update();
+        }
+
+        if state(&app) == GameState::Playing {
+            set_state(&mut app, GameState::Dying);
+        }
+        run_until(&mut app, GameState::GameOver);
+        assert_eq!(count::<With<GameplayObject>>(&mut app), 0);
+
+        // Same as pressing Replay
+        set_state(&mut app, GameState::Starting);
+        app.update();
+        assert_eq!(state(&app), GameState::Starting);
+
+        // The get ready countdown starts over instead of skipping straight
+        // to Playing
+        app.update();
+        assert_eq!(state(&app), GameState::Starting);
+    }
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bubble::game_flow::GameplayObject;
+use bubble::{
+    Collider, CollisionLayer, GameConfig, GameState, HeadlessPlugins, Pickup, PickupKind, PowerUps,
+    RngPlugin, Ship,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+const DRAWS: u32 = 10_000;
+
+fn config(text: &str) -> GameConfig {
+    ron::de::from_str(text).unwrap()
+}
+
+#[test]
+fn kinds_are_picked_in_proportion_to_their_weights() {
+    let config = config(
+        "(pickups: (weights: [(RapidFire, 3.0), (Shield, 1.0), (Repair, 0.0), (FastRegen, -2.0)]))",
+    );
+    let mut rng = StdRng::seed_from_u64(21);
+
+    let mut counts = [0; 4];
+    for _ in 0..DRAWS {
+        let index = match PickupKind::random(&mut rng, &config.pickups).unwrap() {
+            PickupKind::RapidFire => 0,
+            PickupKind::Shield => 1,
+            PickupKind::Repair => 2,
+            PickupKind::FastRegen => 3,
+            PickupKind::WideSpread => unreachable!("not in the weights"),
+        };
+        counts[index] += 1;
+    }
+
+    // Zero and negative weights never come up
+    assert_eq!(counts[2..], [0, 0]);
+    let rapid_fire_share = counts[0] as f32 / DRAWS as f32;
+    assert!(
+        (rapid_fire_share - 0.75).abs() < 0.02,
+        "rapid fire share {rapid_fire_share}"
+    );
+}
+
+#[test]
+fn nothing_is_picked_without_a_positive_weight() {
+    let mut rng = StdRng::seed_from_u64(21);
+    for text in [
+        "(pickups: (weights: []))",
+        "(pickups: (weights: [(Shield, 0.0), (Repair, -1.0)]))",
+    ] {
+        assert_eq!(PickupKind::random(&mut rng, &config(text).pickups), None);
+    }
+}
+
+fn shield_remaining(app: &mut App) -> Option<f32> {
+    app.world_mut()
+        .query_filtered::<&PowerUps, With<Ship>>()
+        .single(app.world())
+        .remaining(PickupKind::Shield)
+}
+
+#[test]
+fn collected_power_up_runs_out_after_its_duration() {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(5) }));
+    app.insert_resource(config("(waves: (list: []), pickups: (duration: 1.0))"));
+    while *app.world().resource::<State<GameState>>().get() != GameState::Playing {
+        app.update();
+    }
+
+    // Drop a shield right on the ship
+    app.world_mut().spawn((
+        Pickup {
+            kind: PickupKind::Shield,
+            lifetime: Timer::from_seconds(10.0, TimerMode::Once),
+        },
+        Transform::default(),
+        Collider {
+            radius: 10.0,
+            mass: 1.0,
+            layer: CollisionLayer::Pickup,
+        },
+        GameplayObject,
+    ));
+
+    for _ in 0..3 {
+        app.update();
+    }
+    let remaining = shield_remaining(&mut app).expect("shield was not collected");
+    assert!(remaining > 0.9 && remaining <= 1.0, "{remaining}");
+
+    for _ in 0..30 {
+        app.update();
+    }
+    assert!(shield_remaining(&mut app).is_some_and(|remaining| remaining < 0.6));
+
+    for _ in 0..40 {
+        app.update();
+    }
+    assert_eq!(shield_remaining(&mut app), None);
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use bubble::spatial::SpatialGrid;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+const CELL_SIZE: f32 = 64.0;
+
+struct Body {
+    entity: Entity,
+    pos: Vec2,
+    radius: f32,
+}
+
+// Random bodies, a share of them centered right on cell borders and corners
+// so their circles straddle neighbouring cells
+fn random_bodies(rng: &mut StdRng, count: u32) -> Vec<Body> {
+    (0..count)
+        .map(|index| {
+            let pos = if index % 3 == 0 {
+                let cell = IVec2::new(rng.gen_range(-10..=10), rng.gen_range(-6..=6));
+                let nudge = Vec2::new(rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0));
+                cell.as_vec2() * CELL_SIZE + nudge
+            } else {
+                Vec2::new(rng.gen_range(-640.0..=640.0), rng.gen_range(-360.0..=360.0))
+            };
+            Body {
+                entity: Entity::from_raw(index),
+                pos,
+                radius: rng.gen_range(2.0..=60.0),
+            }
+        })
+        .collect()
+}
+
+fn overlapping_pairs(
+    bodies: &[Body],
+    candidates: impl Fn(&Body) -> Vec<Entity>,
+) -> BTreeSet<(Entity, Entity)> {
+    let mut pairs = BTreeSet::new();
+    for body in bodies {
+        for other in candidates(body) {
+            let other = &bodies[other.index() as usize];
+            if other.entity != body.entity
+                && body.pos.distance(other.pos) < body.radius + other.radius
+            {
+                pairs.insert((body.entity.min(other.entity), body.entity.max(other.entity)));
+            }
+        }
+    }
+    pairs
+}
+
+#[test]
+fn grid_finds_the_same_pairs_as_brute_force() {
+    let mut rng = StdRng::seed_from_u64(11);
+    let bodies = random_bodies(&mut rng, 500);
+    let mut grid = SpatialGrid::new(CELL_SIZE);
+    for body in &bodies {
+        grid.insert(body.entity, body.pos, body.radius);
+    }
+
+    // Same lookup as the collision pass
+    let from_grid = overlapping_pairs(&bodies, |body| {
+        grid.query(body.pos, body.radius + grid.max_radius())
+            .map(|(entity, _)| entity)
+            .collect()
+    });
+    let brute_force =
+        overlapping_pairs(&bodies, |_| bodies.iter().map(|body| body.entity).collect());
+
+    assert!(!brute_force.is_empty());
+    assert_eq!(from_grid, brute_force);
+}
+
+#[test]
+fn query_matches_a_scan_of_every_point() {
+    let mut rng = StdRng::seed_from_u64(12);
+    let bodies = random_bodies(&mut rng, 300);
+
+    let mut grid = SpatialGrid::new(CELL_SIZE);
+    for body in &bodies {
+        grid.insert(body.entity, body.pos, body.radius);
+    }
+
+    for _ in 0..200 {
+        let center = Vec2::new(rng.gen_range(-700.0..=700.0), rng.gen_range(-400.0..=400.0));
+        let radius = rng.gen_range(0.0..=200.0);
+
+        let found: BTreeSet<Entity> = grid
+            .query(center, radius)
+            .map(|(entity, _)| entity)
+            .collect();
+        let expected: BTreeSet<Entity> = bodies
+            .iter()
+            .filter(|body| body.pos.distance_squared(center) <= radius * radius)
+            .map(|body| body.entity)
+            .collect();
+        assert_eq!(found, expected, "query at {center} with radius {radius}");
+    }
+}
@@ -0,0 +1,163 @@
+use bevy::ecs::system::RunSystemOnce;
+use bevy::prelude::*;
+use bubble::enemy::floater::FLOATER;
+use bubble::enemy::spawn_enemy;
+use bubble::game_flow::GameplayObject;
+use bubble::{
+    Bubble, BubbleWeapon, Collider, CollisionLayer, Enemy, EnemyRegistry, GameConfig, GameState,
+    HeadlessPlugins, RngPlugin, Trapped, Velocity,
+};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+// Every hit counts towards the trap, and two are enough
+const CONFIG: &str = "(
+    waves: (list: []),
+    trap: (weak_health: 1.0, hits_to_trap: 2, drift_speed: 20.0, impact_speed: 120.0, impact_damage: 10.0),
+)";
+
+// A running round with no waves, so the only enemies are the ones placed here
+fn playing_app() -> App {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(24) }));
+    app.insert_resource(ron::de::from_str::<GameConfig>(CONFIG).unwrap());
+    while *app.world().resource::<State<GameState>>().get() != GameState::Playing {
+        app.update();
+    }
+    app
+}
+
+// Place a floater standing still at `pos`
+fn spawn_floater(app: &mut App, pos: Vec2) -> Entity {
+    app.world_mut()
+        .run_system_once(
+            move |mut commands: Commands, registry: Res<EnemyRegistry>, config: Res<GameConfig>| {
+                let behavior = registry.get(&FLOATER).unwrap();
+                let mut rng = StdRng::seed_from_u64(0);
+                spawn_enemy(&mut commands, &mut rng, &config, behavior, pos, 1.0);
+            },
+        )
+        .unwrap();
+
+    let (entity, mut velocity) = app
+        .world_mut()
+        .query_filtered::<(Entity, &Transform, &mut Velocity), With<Enemy>>()
+        .iter_mut(app.world_mut())
+        .find(|(_, transform, _)| transform.translation.truncate() == pos)
+        .map(|(entity, _, velocity)| (entity, velocity))
+        .unwrap();
+    velocity.0 = Vec2::ZERO;
+    entity
+}
+
+fn spawn_bubble(app: &mut App, pos: Vec2, velocity: Vec2) {
+    app.world_mut().spawn((
+        Bubble {
+            weapon: BubbleWeapon::Standard,
+            color: Color::WHITE,
+            size: 1.0,
+            lifetime: Timer::from_seconds(10.0, TimerMode::Once),
+        },
+        Transform::from_xyz(pos.x, pos.y, 0.0),
+        Velocity(velocity),
+        Collider {
+            radius: 1.0,
+            mass: 1.0,
+            layer: CollisionLayer::Bubble,
+        },
+        GameplayObject,
+    ));
+}
+
+fn run(app: &mut App, updates: u32) {
+    for _ in 0..updates {
+        app.update();
+    }
+}
+
+#[test]
+fn weakened_enemy_is_trapped_after_enough_hits() {
+    let mut app = playing_app();
+    let pos = Vec2::new(200.0, 0.0);
+    let enemy = spawn_floater(&mut app, pos);
+
+    spawn_bubble(&mut app, pos, Vec2::X * 100.0);
+    run(&mut app, 3);
+    assert!(app.world().get::<Trapped>(enemy).is_none());
+
+    spawn_bubble(&mut app, pos, Vec2::X * 100.0);
+    run(&mut app, 3);
+    let trapped = app.world().get::<Trapped>(enemy).expect("not trapped");
+    // Remembers the speed the last hit left it with
+    assert!(trapped.speed > 0.0);
+    assert!(app.world().get::<Velocity>(enemy).unwrap().0.length() <= 20.0 + 1e-3);
+}
+
+#[test]
+fn trapped_enemy_breaks_free_at_its_old_speed() {
+    let mut app = playing_app();
+    let enemy = spawn_floater(&mut app, Vec2::new(200.0, 0.0));
+    app.world_mut().entity_mut(enemy).insert((
+        Trapped {
+            timer: Timer::from_seconds(0.5, TimerMode::Once),
+            speed: 50.0,
+        },
+        Velocity(Vec2::Y * 10.0),
+    ));
+
+    run(&mut app, 20);
+    assert!(app.world().get::<Trapped>(enemy).is_some());
+
+    run(&mut app, 20);
+    assert!(app.world().get::<Trapped>(enemy).is_none());
+    assert_eq!(app.world().get::<Enemy>(enemy).unwrap().trap_hits, 0);
+    let speed = app.world().get::<Velocity>(enemy).unwrap().0.length();
+    assert!((speed - 50.0).abs() < 1e-3, "{speed}");
+}
+
+#[test]
+fn trapped_enemy_shoved_into_another_bursts_and_hurts_it() {
+    let mut app = playing_app();
+    let full_health = app
+        .world()
+        .resource::<GameConfig>()
+        .enemy_kind(&FLOATER)
+        .health;
+    let target = spawn_floater(&mut app, Vec2::new(200.0, 0.0));
+    let trapped = spawn_floater(&mut app, Vec2::new(195.0, 0.0));
+    app.world_mut().entity_mut(trapped).insert((
+        Trapped {
+            timer: Timer::from_seconds(5.0, TimerMode::Once),
+            speed: 0.0,
+        },
+        Velocity(Vec2::X * 400.0),
+    ));
+
+    run(&mut app, 3);
+    assert!(
+        app.world().get_entity(trapped).is_err(),
+        "trapped enemy didn't burst"
+    );
+    assert_eq!(
+        app.world().get::<Enemy>(target).unwrap().health,
+        full_health - 10.0
+    );
+}
+
+#[test]
+fn slow_trapped_enemy_just_bumps_into_another() {
+    let mut app = playing_app();
+    let target = spawn_floater(&mut app, Vec2::new(200.0, 0.0));
+    let trapped = spawn_floater(&mut app, Vec2::new(195.0, 0.0));
+    app.world_mut().entity_mut(trapped).insert((
+        Trapped {
+            timer: Timer::from_seconds(5.0, TimerMode::Once),
+            speed: 0.0,
+        },
+        Velocity(Vec2::X * 20.0),
+    ));
+
+    run(&mut app, 3);
+    assert!(app.world().get::<Trapped>(trapped).is_some());
+    assert!(app.world().get_entity(target).is_ok());
+}
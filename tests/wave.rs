@@ -0,0 +1,58 @@
+use bevy::prelude::*;
+use bubble::wave::wave_config;
+use bubble::{GameConfig, HeadlessPlugins, RngPlugin, WaveDirector};
+
+fn config(text: &str) -> GameConfig {
+    ron::de::from_str(text).unwrap()
+}
+
+#[test]
+fn past_the_list_the_last_wave_repeats_with_more_enemies() {
+    let config = config(
+        "(waves: (
+            repeat_count_growth: 0.5,
+            list: [(groups: [(count: 3)]), (groups: [(count: 5)])],
+        ))",
+    );
+    let count = |wave| {
+        let (wave_config, scale) = wave_config(&config.waves, wave).unwrap();
+        (wave_config.groups[0].count, scale)
+    };
+
+    assert_eq!(count(1), (3, 1.0));
+    assert_eq!(count(2), (5, 1.0));
+    assert_eq!(count(3), (5, 1.5));
+    assert_eq!(count(4), (5, 2.0));
+}
+
+#[test]
+fn no_waves_listed_means_nothing_to_spawn() {
+    let config = config("(waves: (list: []))");
+    assert!(wave_config(&config.waves, 1).is_none());
+}
+
+#[test]
+fn waves_advance_one_at_a_time() {
+    let mut app = App::new();
+    app.add_plugins(HeadlessPlugins.set(RngPlugin { seed: Some(9) }));
+    app.insert_resource(config(
+        "(waves: (
+            start_delay: 0.2,
+            boss_every: 0,
+            list: [(breather: 0.2, groups: [(count: 1, interval: 0.1)])],
+        ))",
+    ));
+
+    let mut waves = vec![0];
+    for _ in 0..60 * 3 {
+        app.update();
+        let wave = app.world().resource::<WaveDirector>().wave;
+        if wave != *waves.last().unwrap() {
+            waves.push(wave);
+        }
+    }
+
+    // Well past the single listed wave, without skipping any
+    assert!(waves.len() > 3, "only reached {waves:?}");
+    assert_eq!(waves, (0..waves.len() as u32).collect::<Vec<_>>());
+}